@@ -0,0 +1,101 @@
+use crate::prelude::*;
+use std::convert::TryFrom;
+
+use crate::vec2::*;
+use crate::vec3::*;
+use crate::mat2::*;
+use crate::mat3::*;
+use crate::error::*;
+use crate::linalg::count;
+
+//a 2d Mat3 with the bottom row fixed at (0, 0, 1) left out: p -> linear * p + translation
+#[repr(C)]
+#[cfg_attr(feature="serde", derive(Serialize,Deserialize))]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct Affine2<T> {
+    pub linear: Mat2<T>,
+    pub translation: Vec2<T>,
+}
+pub fn affine2<T>(linear: Mat2<T>, translation: Vec2<T>) -> Affine2<T> {
+    Affine2 { linear, translation }
+}
+
+impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> Affine2<T>
+    where Vec2<T>: VecOps<T> {
+    pub fn ident() -> Self {
+        affine2(Mat2::ident(), Vec2::zero())
+    }
+    
+    pub fn from_translation(t: Vec2<T>) -> Self {
+        affine2(Mat2::ident(), t)
+    }
+    
+    pub fn from_linear(m: Mat2<T>) -> Self {
+        affine2(m, Vec2::zero())
+    }
+    
+    pub fn transform_point(self, p: Vec2<T>) -> Vec2<T> {
+        self.linear.apply_to(p) + self.translation
+    }
+    
+    pub fn transform_vector(self, v: Vec2<T>) -> Vec2<T> {
+        self.linear.apply_to(v)
+    }
+    
+    //uses the transpose when linear is orthonormal (up to rounding), which is cheaper and more accurate than inverting it
+    pub fn inverse(self) -> Self
+        where T: PartialOrd + Abs + Epsilon {
+        //a few ulps of rounding is expected, anything this misses still gets inverted correctly, just less cheaply
+        if self.is_orthonormal(count::<T>(16) * T::epsilon()) { self.inverse_orthonormal() } else { self.with_linear_inverse(self.linear.inv()) }
+    }
+    
+    //linear must be orthonormal (a rotation or reflection), which isn't checked
+    pub fn inverse_orthonormal(self) -> Self {
+        self.with_linear_inverse(self.linear.transpose())
+    }
+    
+    //every entry of linear * linear.transpose() is within epsilon of the identity
+    pub fn is_orthonormal(self, epsilon: T) -> bool
+        where T: PartialOrd + Abs {
+        let Mat2{ x,y } = self.linear * self.linear.transpose() - Mat2::ident();
+        x.abs().max_elem() <= epsilon && y.abs().max_elem() <= epsilon
+    }
+    
+    fn with_linear_inverse(self, inv: Mat2<T>) -> Self {
+        affine2(inv, -inv.apply_to(self.translation))
+    }
+}
+
+impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> Default for Affine2<T>
+    where Vec2<T>: VecOps<T> {
+    fn default() -> Self {
+        Affine2::ident()
+    }
+}
+
+//(a * b).transform_point(p) == a.transform_point(b.transform_point(p))
+impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> Mul for Affine2<T>
+    where Vec2<T>: VecOps<T> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        affine2(self.linear * other.linear, self.transform_point(other.translation))
+    }
+}
+
+impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> From<Affine2<T>> for Mat3<T>
+    where Vec3<T>: VecOps<T>, Vec2<T>: VecOps<T> {
+    fn from(a: Affine2<T>) -> Self {
+        Mat3::from_mat2_2d(a.linear, a.translation)
+    }
+}
+
+impl<T: Zero + One + PartialEq> TryFrom<Mat3<T>> for Affine2<T> {
+    type Error = NotAffine;
+    fn try_from(m: Mat3<T>) -> Result<Self, NotAffine> {
+        if m.z != vec3(T::zero(), T::zero(), T::one()) {
+            return Err(NotAffine);
+        }
+        let Mat3{ x,y,.. } = m;
+        Ok(affine2(mat2(vec2(x.x, x.y), vec2(y.x, y.y)), vec2(x.z, y.z)))
+    }
+}
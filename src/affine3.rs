@@ -0,0 +1,107 @@
+use crate::prelude::*;
+use std::convert::TryFrom;
+
+use crate::vec2::*;
+use crate::vec3::*;
+use crate::vec4::*;
+use crate::mat3::*;
+use crate::mat4::*;
+use crate::error::*;
+use crate::linalg::count;
+
+//a Mat4 with the bottom row fixed at (0, 0, 0, 1) left out: p -> linear * p + translation
+#[repr(C)]
+#[cfg_attr(feature="serde", derive(Serialize,Deserialize))]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct Affine3<T> {
+    pub linear: Mat3<T>,
+    pub translation: Vec3<T>,
+}
+pub fn affine3<T>(linear: Mat3<T>, translation: Vec3<T>) -> Affine3<T> {
+    Affine3 { linear, translation }
+}
+
+impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> Affine3<T>
+    where Vec3<T>: VecOps<T>, Vec2<T>: VecOps<T> {
+    pub fn ident() -> Self {
+        affine3(Mat3::ident(), Vec3::zero())
+    }
+    
+    pub fn from_translation(t: Vec3<T>) -> Self {
+        affine3(Mat3::ident(), t)
+    }
+    
+    pub fn from_linear(m: Mat3<T>) -> Self {
+        affine3(m, Vec3::zero())
+    }
+    
+    pub fn transform_point(self, p: Vec3<T>) -> Vec3<T> {
+        self.linear.apply_to(p) + self.translation
+    }
+    
+    pub fn transform_vector(self, v: Vec3<T>) -> Vec3<T> {
+        self.linear.apply_to(v)
+    }
+    
+    //uses the transpose when linear is orthonormal (up to rounding), which is cheaper and more accurate than inverting it
+    pub fn inverse(self) -> Self
+        where T: PartialOrd + Abs + Epsilon {
+        //rotations from normalized quaternions come out within about 9 ulps of orthonormal, anything this misses still gets inverted correctly
+        if self.is_orthonormal(count::<T>(16) * T::epsilon()) { self.inverse_orthonormal() } else { self.with_linear_inverse(self.linear.inv()) }
+    }
+    
+    //linear must be orthonormal (a rotation or reflection), which isn't checked
+    pub fn inverse_orthonormal(self) -> Self {
+        self.with_linear_inverse(self.linear.transpose())
+    }
+    
+    //every entry of linear * linear.transpose() is within epsilon of the identity
+    pub fn is_orthonormal(self, epsilon: T) -> bool
+        where T: PartialOrd + Abs {
+        let Mat3{ x,y,z } = self.linear * self.linear.transpose() - Mat3::ident();
+        x.abs().max_elem() <= epsilon && y.abs().max_elem() <= epsilon && z.abs().max_elem() <= epsilon
+    }
+    
+    fn with_linear_inverse(self, inv: Mat3<T>) -> Self {
+        affine3(inv, -inv.apply_to(self.translation))
+    }
+}
+
+impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> Default for Affine3<T>
+    where Vec3<T>: VecOps<T>, Vec2<T>: VecOps<T> {
+    fn default() -> Self {
+        Affine3::ident()
+    }
+}
+
+//(a * b).transform_point(p) == a.transform_point(b.transform_point(p))
+impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> Mul for Affine3<T>
+    where Vec3<T>: VecOps<T>, Vec2<T>: VecOps<T> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        affine3(self.linear * other.linear, self.transform_point(other.translation))
+    }
+}
+
+impl<T: Zero + One> From<Affine3<T>> for Mat4<T> {
+    fn from(a: Affine3<T>) -> Self {
+        let Mat3{ x,y,z } = a.linear;
+        mat4(
+            x.extend(a.translation.x),
+            y.extend(a.translation.y),
+            z.extend(a.translation.z),
+            vec4(T::zero(), T::zero(), T::zero(), T::one()),
+        )
+    }
+}
+
+impl<T: Zero + One + PartialEq> TryFrom<Mat4<T>> for Affine3<T> {
+    type Error = NotAffine;
+    fn try_from(m: Mat4<T>) -> Result<Self, NotAffine> {
+        if m.w != vec4(T::zero(), T::zero(), T::zero(), T::one()) {
+            return Err(NotAffine);
+        }
+        let Mat4{ x,y,z,.. } = m;
+        Ok(affine3(mat3(vec3(x.x, x.y, x.z), vec3(y.x, y.y, y.z), vec3(z.x, z.y, z.z)), vec3(x.w, y.w, z.w)))
+    }
+}
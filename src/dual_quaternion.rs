@@ -0,0 +1,199 @@
+use crate::prelude::*;
+
+use crate::vec3::*;
+use crate::vec4::*;
+use crate::mat4::*;
+use crate::quaternion::*;
+
+//real + e * dual with e^2 = 0, a unit dual quaternion is a rigid transform (rotation then translation)
+//composition and point transforms follow Quaternion, so (a * b).transform_point(p) applies b first
+#[repr(C)]
+#[cfg_attr(feature="serde", derive(Serialize,Deserialize))]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct DualQuaternion<T> {
+    pub real: Quaternion<T>,
+    pub dual: Quaternion<T>,
+}
+pub fn dual_quaternion<T>(real: Quaternion<T>, dual: Quaternion<T>) -> DualQuaternion<T> {
+    DualQuaternion { real, dual }
+}
+
+impl<T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Trig + Sqrt<T> + Neg<Output=T> + Abs> DualQuaternion<T> {
+    pub fn ident() -> Self {
+        dual_quaternion(Quaternion::ident(), Quaternion::zero())
+    }
+    
+    //rotation must be normalized
+    pub fn from_rotation_translation(rotation: Quaternion<T>, translation: Vec3<T>) -> Self {
+        let t = quaternion(T::zero(), translation.x, translation.y, translation.z);
+        dual_quaternion(rotation, t * rotation / T::two())
+    }
+    
+    pub fn from_translation(translation: Vec3<T>) -> Self {
+        Self::from_rotation_translation(Quaternion::ident(), translation)
+    }
+    
+    pub fn rotation(self) -> Quaternion<T> {
+        self.real
+    }
+    
+    //self must be normalized
+    pub fn translation(self) -> Vec3<T> {
+        let t = self.dual * self.real.conjugate() * T::two();
+        vec3(t.i, t.j, t.k)
+    }
+    
+    pub fn conjugate(self) -> Self {
+        dual_quaternion(self.real.conjugate(), self.dual.conjugate())
+    }
+    
+    pub fn inv(self) -> Self {
+        let real = self.real.inv();
+        dual_quaternion(real, -(real * self.dual * real))
+    }
+    
+    //the inverse of a normalized dual quaternion is just its conjugate
+    pub fn inv_unit(self) -> Self {
+        self.conjugate()
+    }
+    
+    //divides out the magnitude of real and removes the part of dual that isn't orthogonal to it, so self is a rigid transform again
+    pub fn normalize(self) -> Self {
+        let mag = self.real.magnitude();
+        let real = self.real / mag;
+        let dual = self.dual / mag;
+        dual_quaternion(real, dual - real * real.dot(dual))
+    }
+    
+    //self must be normalized
+    pub fn transform_point(self, p: Vec3<T>) -> Vec3<T> {
+        self.real.rotate(p) + self.translation()
+    }
+    
+    //rotation only, self must be normalized
+    pub fn transform_vector(self, v: Vec3<T>) -> Vec3<T> {
+        self.real.rotate(v)
+    }
+    
+    //self must be normalized
+    pub fn to_mat4(self) -> Mat4<T> {
+        let t = self.translation();
+        self.real.rot_mat().extend(t, vec4(T::zero(), T::zero(), T::zero(), T::one()))
+    }
+    
+    //m must be a rotation and translation, anything in the bottom row is ignored
+    pub fn from_mat4(m: Mat4<T>) -> Self
+        where T: PartialOrd {
+        Self::from_rotation_translation(Quaternion::from_rot_mat(m.downsize()), vec3(m.x.w, m.y.w, m.z.w))
+    }
+    
+    //dual quaternion linear blending for skinning: the weighted sum of the transforms renormalized
+    //each one is flipped into the same hemisphere as the first so opposite signs of the same rotation don't cancel out
+    pub fn blend<I: IntoIterator<Item=(Self, T)>>(weighted: I) -> Self
+        where T: PartialOrd {
+        let mut iter = weighted.into_iter();
+        let (first, w) = match iter.next() {
+            Some(x) => x,
+            None => return Self::ident(),
+        };
+        let mut sum = first * w;
+        for (dq, w) in iter {
+            sum = sum + if first.real.dot(dq.real) < T::zero() { dq * -w } else { dq * w };
+        }
+        sum.normalize()
+    }
+    
+    //screw linear interpolation: constant speed rotation and translation along a single screw axis, both must be normalized
+    pub fn sclerp(self, other: Self, t: T) -> Self
+        where T: PartialOrd + Epsilon {
+        let mut d = self.conjugate() * other;
+        if d.real.r < T::zero() {
+            d = -d;
+        }
+        self * d.pow_unit(t)
+    }
+    
+    //self must be normalized and have a non-negative real.r, found from the screw parameters: angle theta and distance s along the axis l with moment m
+    fn pow_unit(self, t: T) -> Self
+        where T: PartialOrd + Epsilon {
+        let v = vec3(self.real.i, self.real.j, self.real.k);
+        let sin = v.magnitude();
+        if sin <= T::epsilon().sqrt() {
+            //no rotation to speak of, so the screw axis is lost in rounding, scale the translation instead
+            let real = Quaternion::ident().nlerp(self.real, t);
+            return Self::from_rotation_translation(real, self.translation() * t);
+        }
+        let l = v / sin;
+        let theta = T::two() * sin.atan2(self.real.r);
+        let s = -T::two() * self.dual.r / sin;
+        let m = (vec3(self.dual.i, self.dual.j, self.dual.k) - l * (s / T::two() * self.real.r)) / sin;
+        let (theta, s) = (theta * t, s * t);
+        let (sin, cos) = (theta / T::two()).sin_cos();
+        let real_v = l * sin;
+        let dual_v = m * sin + l * (s / T::two() * cos);
+        dual_quaternion(quaternion(cos, real_v.x, real_v.y, real_v.z), quaternion(-s / T::two() * sin, dual_v.x, dual_v.y, dual_v.z))
+    }
+}
+
+impl<T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Trig + Sqrt<T> + Neg<Output=T> + Abs> Default for DualQuaternion<T> {
+    fn default() -> Self {
+        DualQuaternion::ident()
+    }
+}
+
+impl<T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Trig + Sqrt<T> + Neg<Output=T> + Abs> From<DualQuaternion<T>> for Mat4<T> {
+    fn from(dq: DualQuaternion<T>) -> Self {
+        dq.to_mat4()
+    }
+}
+
+impl<T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Trig + Sqrt<T> + Neg<Output=T> + Abs + PartialOrd> From<Mat4<T>> for DualQuaternion<T> {
+    fn from(m: Mat4<T>) -> Self {
+        DualQuaternion::from_mat4(m)
+    }
+}
+
+impl<T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T>> Mul for DualQuaternion<T> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        dual_quaternion(self.real * other.real, self.real * other.dual + self.dual * other.real)
+    }
+}
+
+impl<T: Copy + Mul<Output=T>> Mul<T> for DualQuaternion<T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        dual_quaternion(self.real * rhs, self.dual * rhs)
+    }
+}
+
+impl<T: Add<Output=T>> Add for DualQuaternion<T> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        dual_quaternion(self.real + other.real, self.dual + other.dual)
+    }
+}
+
+impl<T: Sub<Output=T>> Sub for DualQuaternion<T> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        dual_quaternion(self.real - other.real, self.dual - other.dual)
+    }
+}
+
+impl<T: Neg> Neg for DualQuaternion<T> {
+    type Output = DualQuaternion<<T as Neg>::Output>;
+    fn neg(self) -> Self::Output {
+        dual_quaternion(-self.real, -self.dual)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for DualQuaternion<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(f, "({2:.*}) + e({3:.*})", p, p, self.real, self.dual)
+        } else {
+            write!(f, "({}) + e({})", self.real, self.dual)
+        }
+    }
+}
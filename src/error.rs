@@ -0,0 +1,32 @@
+use crate::prelude::*;
+
+#[cfg_attr(feature="serde", derive(Serialize,Deserialize))]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum InvError<T> {
+    Singular { det: T },
+    NonIntegral { det: T }, //only returned for integer matrices, the inverse exists but has fractional entries
+}
+
+impl<T: fmt::Display> fmt::Display for InvError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InvError::Singular { det } => write!(f, "matrix is singular (det = {})", det),
+            InvError::NonIntegral { det } => write!(f, "matrix inverse is not integral (det = {})", det),
+        }
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> std::error::Error for InvError<T> {}
+
+//returned when converting a matrix to an Affine2/Affine3 whose bottom row isn't (0, ..., 0, 1)
+#[cfg_attr(feature="serde", derive(Serialize,Deserialize))]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct NotAffine;
+
+impl fmt::Display for NotAffine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "matrix is not affine (the bottom row must be (0, ..., 0, 1))")
+    }
+}
+
+impl std::error::Error for NotAffine {}
@@ -0,0 +1,70 @@
+use crate::prelude::*;
+
+//the axes of a rotation sequence, the first six are tait-bryan angles and the last six are proper euler angles
+#[cfg_attr(feature="serde", derive(Serialize,Deserialize))]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum EulerAxes {
+    XYZ, XZY, YXZ, YZX, ZXY, ZYX,
+    XYX, XZX, YXY, YZY, ZXZ, ZYZ,
+}
+
+//intrinsic rotations are about the rotated (body) axes, extrinsic ones are about the fixed world axes
+//angles are always given in the order of the axes, so Intrinsic(XYZ) with vec3(a, b, c) is rotate_x(a) * rotate_y(b) * rotate_z(c)
+//and Extrinsic(XYZ) with the same angles is rotate_z(c) * rotate_y(b) * rotate_x(a)
+#[cfg_attr(feature="serde", derive(Serialize,Deserialize))]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum EulerOrder {
+    Intrinsic(EulerAxes),
+    Extrinsic(EulerAxes),
+}
+
+impl EulerAxes {
+    //0 = x, 1 = y, 2 = z
+    pub fn axes(self) -> [usize; 3] {
+        use EulerAxes::*;
+        match self {
+            XYZ => [0, 1, 2],
+            XZY => [0, 2, 1],
+            YXZ => [1, 0, 2],
+            YZX => [1, 2, 0],
+            ZXY => [2, 0, 1],
+            ZYX => [2, 1, 0],
+            XYX => [0, 1, 0],
+            XZX => [0, 2, 0],
+            YXY => [1, 0, 1],
+            YZY => [1, 2, 1],
+            ZXZ => [2, 0, 2],
+            ZYZ => [2, 1, 2],
+        }
+    }
+    
+    //true when the first and last axes are the same
+    pub fn is_proper(self) -> bool {
+        let [i, _, k] = self.axes();
+        i == k
+    }
+    
+    pub fn reverse(self) -> Self {
+        use EulerAxes::*;
+        match self {
+            XYZ => ZYX,
+            XZY => YZX,
+            YXZ => ZXY,
+            YZX => XZY,
+            ZXY => YXZ,
+            ZYX => XYZ,
+            proper => proper,
+        }
+    }
+}
+
+impl EulerOrder {
+    //an extrinsic sequence is the intrinsic one with the axes and angles reversed
+    //returns the intrinsic axes and whether the angles have to be reversed
+    pub fn to_intrinsic(self) -> (EulerAxes, bool) {
+        match self {
+            EulerOrder::Intrinsic(axes) => (axes, false),
+            EulerOrder::Extrinsic(axes) => (axes.reverse(), true),
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use crate::prelude::*;
+
+use crate::quaternion::*;
+
+//a hurwitz quaternion: the components are either all integers (a lipschitz quaternion) or all halves of odd integers
+//unlike the lipschitz quaternions these have euclidean division, so gcds and prime factorizations exist
+//stored doubled so every component is an integer, they all have the same parity
+#[repr(C)]
+#[cfg_attr(feature="serde", derive(Serialize,Deserialize))]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct Hurwitz<T> {
+    doubled: Quaternion<T>,
+}
+
+impl<T: Scalar + Two + Euclid> Hurwitz<T> {
+    pub fn new(q: Quaternion<T>) -> Self {
+        Hurwitz { doubled: q * T::two() }
+    }
+    
+    //None if the components don't all have the same parity
+    pub fn from_doubled(doubled: Quaternion<T>) -> Option<Self> {
+        let parity = doubled.r.rem_euclid(T::two());
+        if (1..4).all(|n| doubled[n].rem_euclid(T::two()) == parity) {
+            Some(Hurwitz { doubled })
+        } else {
+            None
+        }
+    }
+    
+    pub fn doubled(self) -> Quaternion<T> {
+        self.doubled
+    }
+    
+    pub fn is_lipschitz(self) -> bool {
+        self.doubled.r.rem_euclid(T::two()) == T::zero()
+    }
+    
+    pub fn to_lipschitz(self) -> Option<Quaternion<T>> {
+        if self.is_lipschitz() { Some(self.doubled / T::two()) } else { None }
+    }
+    
+    pub fn conjugate(self) -> Self {
+        Hurwitz { doubled: self.doubled.conjugate() }
+    }
+    
+    //always an integer
+    pub fn norm(self) -> T {
+        self.doubled.norm_squared() / (T::two() * T::two())
+    }
+    
+    pub fn is_unit(self) -> bool {
+        self.norm() == T::one()
+    }
+    
+    //self = q * rhs + r with r.norm() < rhs.norm(), panics if rhs is 0 for integers
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        //self * rhs.inv() = p / n, rounded to the nearest lipschitz or half integer quaternion whichever is closer
+        let p = self.doubled * rhs.doubled.conjugate();
+        let n = rhs.doubled.norm_squared();
+        let two = T::two();
+        let round = |x: T| two * (two * x + n).div_euclid(two * n);
+        let round_half = |x: T| two * x.div_euclid(n) + T::one();
+        let whole = quaternion(round(p.r), round(p.i), round(p.j), round(p.k));
+        let half = quaternion(round_half(p.r), round_half(p.i), round_half(p.j), round_half(p.k));
+        //compare the remainders rather than the distances to p, their norms are bounded by rhs.norm() so this can't overflow
+        let rem = |q: Quaternion<T>| self.doubled - (q * rhs.doubled) / two;
+        let (r_whole, r_half) = (rem(whole), rem(half));
+        if r_whole.norm_squared() <= r_half.norm_squared() {
+            (Hurwitz { doubled: whole }, Hurwitz { doubled: r_whole })
+        } else {
+            (Hurwitz { doubled: half }, Hurwitz { doubled: r_half })
+        }
+    }
+    
+    //self / rhs with the rhs on the right (self = q * rhs) if it is a hurwitz quaternion
+    pub fn exact_div(self, rhs: Self) -> Option<Self> {
+        let (q, r) = self.div_rem(rhs);
+        if r.doubled == Quaternion::zero() { Some(q) } else { None }
+    }
+    
+    //greatest common right divisor, self = a * g and other = b * g, only unique up to multiplying by one of the 24 units
+    pub fn gcd(self, other: Self) -> Self {
+        let (mut a, mut b) = (self, other);
+        while b.doubled != Quaternion::zero() {
+            let (_, r) = a.div_rem(b);
+            a = b;
+            b = r;
+        }
+        a
+    }
+    
+    //splits self into primes (their norms are prime numbers) with self = factors[0] * factors[1] * ...
+    //uses trial division on the norm, so it is only meant for small norms
+    pub fn factor(self) -> Vec<Self> {
+        let mut rest = self;
+        let mut n = self.norm();
+        let mut p = T::two();
+        //right factors, the one found first is the rightmost
+        let mut factors = Vec::new();
+        while n > T::one() {
+            if p * p > n {
+                p = n;
+            }
+            if n.rem_euclid(p) != T::zero() {
+                p = p + T::one();
+                continue;
+            }
+            let real_p = Hurwitz::new(quaternion(p, T::zero(), T::zero(), T::zero()));
+            let g = rest.gcd(real_p);
+            if g.norm() == p {
+                rest = rest.exact_div(g).unwrap();
+                factors.push(g);
+                n = n / p;
+            } else {
+                //p divides rest, which can't be split off one prime at a time, so use p = pi.conjugate() * pi
+                let pi = Self::prime_of_norm(p);
+                rest = rest.exact_div(real_p).unwrap();
+                factors.push(pi);
+                factors.push(pi.conjugate());
+                n = n / (p * p);
+            }
+        }
+        //rest is now a unit (or 0)
+        factors.reverse();
+        match factors.first_mut() {
+            Some(first) => *first = rest * *first,
+            None => factors.push(rest),
+        }
+        factors
+    }
+    
+    //some quaternion with a norm of p, p must be prime
+    pub fn prime_of_norm(p: T) -> Self {
+        //there are always x, y with x^2 + y^2 + 1 = 0 (mod p), and the gcd of x + yi + j with p has norm p
+        let real_p = Hurwitz::new(quaternion(p, T::zero(), T::zero(), T::zero()));
+        let mut x = T::zero();
+        while x < p {
+            let mut y = T::zero();
+            while y < p {
+                if (x * x + y * y + T::one()).rem_euclid(p) == T::zero() {
+                    return Hurwitz::new(quaternion(x, y, T::one(), T::zero())).gcd(real_p);
+                }
+                y = y + T::one();
+            }
+            x = x + T::one();
+        }
+        panic!("prime_of_norm needs a prime")
+    }
+}
+
+impl<T: Scalar + Two + Euclid> From<Quaternion<T>> for Hurwitz<T> {
+    fn from(q: Quaternion<T>) -> Self {
+        Hurwitz::new(q)
+    }
+}
+
+impl<T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Two> Mul for Hurwitz<T> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        //(a / 2) * (b / 2) doubled is a * b / 2, which is always whole
+        Hurwitz { doubled: (self.doubled * other.doubled) / T::two() }
+    }
+}
+
+impl<T: Add<Output=T>> Add for Hurwitz<T> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Hurwitz { doubled: self.doubled + other.doubled }
+    }
+}
+
+impl<T: Sub<Output=T>> Sub for Hurwitz<T> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Hurwitz { doubled: self.doubled - other.doubled }
+    }
+}
+
+impl<T: Neg> Neg for Hurwitz<T> {
+    type Output = Hurwitz<<T as Neg>::Output>;
+    fn neg(self) -> Self::Output {
+        Hurwitz { doubled: -self.doubled }
+    }
+}
+
+impl<T: Scalar + Two + Euclid + fmt::Display> fmt::Display for Hurwitz<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.to_lipschitz() {
+            Some(q) => write!(f, "{}", q),
+            None => write!(f, "({}) / 2", self.doubled),
+        }
+    }
+}
@@ -1,15 +1,25 @@
 #![allow(incomplete_features)]
-#![feature(decl_macro,specialization,generic_arg_infer)]
+#![feature(decl_macro,specialization)]
 
 pub mod vec2;
 pub mod vec3;
 pub mod vec4;
+pub mod vecn;
 pub mod mat2;
 pub mod mat3;
 pub mod mat4;
+pub mod affine2;
+pub mod affine3;
+pub mod matn;
 pub mod quaternion;
+pub mod hurwitz;
+pub mod dual_quaternion;
+pub mod euler;
+pub mod error;
+pub mod linalg;
 pub mod traits;
 #[macro_use]
 mod vector;
+mod swizzle;
 
 mod prelude;
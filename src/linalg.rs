@@ -0,0 +1,434 @@
+#![allow(clippy::needless_range_loop)]
+use crate::prelude::*;
+
+//the decompositions are written once over arrays, impl_linalg! wires them up to Mat2/Mat3/Mat4
+
+//P * A = L * U, with l unit lower triangular and u upper triangular
+//row i of P * A is row perm[i] of A
+#[derive(Debug,Copy,Clone,PartialEq)]
+pub struct Lu<M, const N: usize> {
+    pub l: M,
+    pub u: M,
+    pub perm: [usize; N],
+    pub rank: usize,
+    pub odd: bool, //whether perm is an odd permutation, flips the sign of det
+}
+
+//n as a T, there is no conversion from usize in the Scalar bounds
+pub(crate) fn count<T: Scalar>(n: usize) -> T {
+    let mut r = T::zero();
+    for _ in 0..n {
+        r = r + T::one();
+    }
+    r
+}
+
+//pivots with magnitude at or below N * max|a| * epsilon are treated as zero
+pub(crate) fn tolerance<T: Scalar, const N: usize>(a: &[[T; N]; N]) -> T {
+    let mut max = T::zero();
+    for row in a {
+        for &x in row {
+            max = max.non_nan_max(x.abs());
+        }
+    }
+    count::<T>(N) * max * T::epsilon()
+}
+
+//divisions use exact_div, so for integers this is None whenever a multiplier would be fractional
+pub(crate) fn lu<T: Scalar + ExactDiv, const N: usize>(mut a: [[T; N]; N]) -> Option<Lu<[[T; N]; N], N>> {
+    let tol = tolerance(&a);
+    let mut perm = [0; N];
+    for (i, p) in perm.iter_mut().enumerate() {
+        *p = i;
+    }
+    let (mut rank, mut odd) = (0, false);
+    for k in 0..N {
+        let mut p = k;
+        for i in k + 1..N {
+            if a[i][k].abs() > a[p][k].abs() {
+                p = i;
+            }
+        }
+        if p != k {
+            a.swap(p, k);
+            perm.swap(p, k);
+            odd = !odd;
+        }
+        if a[k][k].abs() <= tol {
+            for row in a.iter_mut().skip(k + 1) {
+                row[k] = T::zero();
+            }
+            continue;
+        }
+        rank += 1;
+        for i in k + 1..N {
+            let f = a[i][k].exact_div(a[k][k])?;
+            a[i][k] = f;
+            for j in k + 1..N {
+                a[i][j] = a[i][j] - f * a[k][j];
+            }
+        }
+    }
+    let mut l = [[T::zero(); N]; N];
+    let mut u = [[T::zero(); N]; N];
+    for i in 0..N {
+        for j in 0..N {
+            if j < i {
+                l[i][j] = a[i][j];
+            } else {
+                u[i][j] = a[i][j];
+            }
+        }
+        l[i][i] = T::one();
+    }
+    Some(Lu { l, u, perm, rank, odd })
+}
+
+//solves L * y = b for lower triangular L, None if a division isn't exact
+pub(crate) fn forward_sub<T: Scalar + ExactDiv, const N: usize>(l: &[[T; N]; N], mut b: [T; N]) -> Option<[T; N]> {
+    for i in 0..N {
+        for j in 0..i {
+            b[i] = b[i] - l[i][j] * b[j];
+        }
+        b[i] = b[i].exact_div(l[i][i])?;
+    }
+    Some(b)
+}
+
+//solves U * x = b for upper triangular U, None if a division isn't exact
+pub(crate) fn back_sub<T: Scalar + ExactDiv, const N: usize>(u: &[[T; N]; N], mut b: [T; N]) -> Option<[T; N]> {
+    for i in (0..N).rev() {
+        for j in i + 1..N {
+            b[i] = b[i] - u[i][j] * b[j];
+        }
+        b[i] = b[i].exact_div(u[i][i])?;
+    }
+    Some(b)
+}
+
+//fraction-free (bareiss) elimination for when lu has no integer multipliers
+//every division is exact, each entry is a minor of [a | b], so the only None besides rank deficiency is a fractional solution
+pub(crate) fn bareiss_solve<T: Scalar + ExactDiv, const N: usize>(mut a: [[T; N]; N], mut b: [T; N]) -> Option<[T; N]> {
+    let mut prev = T::one();
+    for k in 0..N {
+        let p = (k..N).find(|&i| a[i][k] != T::zero())?;
+        a.swap(p, k);
+        b.swap(p, k);
+        for i in k + 1..N {
+            for j in k + 1..N {
+                a[i][j] = (a[k][k] * a[i][j] - a[i][k] * a[k][j]).exact_div(prev)?;
+            }
+            b[i] = (a[k][k] * b[i] - a[i][k] * b[k]).exact_div(prev)?;
+            a[i][k] = T::zero();
+        }
+        prev = a[k][k];
+    }
+    back_sub(&a, b)
+}
+
+pub(crate) fn transpose<T: Copy, const N: usize>(a: [[T; N]; N]) -> [[T; N]; N] {
+    let mut r = a;
+    for i in 0..N {
+        for j in 0..N {
+            r[i][j] = a[j][i];
+        }
+    }
+    r
+}
+
+//only reads the lower triangle, so A is assumed symmetric
+pub(crate) fn cholesky<T: Scalar + Sqrt<T>, const N: usize>(a: [[T; N]; N]) -> Option<[[T; N]; N]> {
+    let tol = tolerance(&a);
+    let mut l = [[T::zero(); N]; N];
+    for j in 0..N {
+        let mut d = a[j][j];
+        for k in 0..j {
+            d = d - l[j][k] * l[j][k];
+        }
+        if d <= tol || d.is_nan() {
+            return None;
+        }
+        l[j][j] = d.sqrt();
+        for i in j + 1..N {
+            let mut s = a[i][j];
+            for k in 0..j {
+                s = s - l[i][k] * l[j][k];
+            }
+            l[i][j] = s / l[j][j];
+        }
+    }
+    Some(l)
+}
+
+pub(crate) fn ldlt<T: Scalar + ExactDiv, const N: usize>(a: [[T; N]; N]) -> Option<([[T; N]; N], [T; N])> {
+    let tol = tolerance(&a);
+    let mut l = [[T::zero(); N]; N];
+    let mut d = [T::zero(); N];
+    for j in 0..N {
+        d[j] = a[j][j];
+        for k in 0..j {
+            d[j] = d[j] - l[j][k] * l[j][k] * d[k];
+        }
+        if d[j].abs() <= tol || d[j].is_nan() {
+            return None;
+        }
+        l[j][j] = T::one();
+        for i in j + 1..N {
+            let mut s = a[i][j];
+            for k in 0..j {
+                s = s - l[i][k] * l[j][k] * d[k];
+            }
+            l[i][j] = s.exact_div(d[j])?;
+        }
+    }
+    Some((l, d))
+}
+
+//cyclic jacobi for symmetric A, returns the eigenvalues and the eigenvectors as the columns of V
+//only reads the upper triangle
+pub(crate) fn jacobi_eigen<T: Scalar + Sqrt<T>, const N: usize>(mut a: [[T; N]; N], max_sweeps: usize) -> ([T; N], [[T; N]; N]) {
+    let mut v = [[T::zero(); N]; N];
+    for i in 0..N {
+        v[i][i] = T::one();
+        for j in 0..i {
+            a[i][j] = a[j][i];
+        }
+    }
+    let mut norm = T::zero();
+    for row in &a {
+        for &x in row {
+            norm = norm + x * x;
+        }
+    }
+    let tol = norm * T::epsilon() * T::epsilon();
+    for _ in 0..max_sweeps {
+        let mut off = T::zero();
+        for p in 0..N {
+            for q in p + 1..N {
+                off = off + a[p][q] * a[p][q];
+            }
+        }
+        if off <= tol {
+            break;
+        }
+        for p in 0..N {
+            for q in p + 1..N {
+                if a[p][q] == T::zero() {
+                    continue;
+                }
+                let two = T::one() + T::one();
+                let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                let t = T::one() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                let t = if theta < T::zero() { -t } else { t };
+                let c = T::one() / (t * t + T::one()).sqrt();
+                let s = t * c;
+                let apq = a[p][q];
+                a[p][p] = a[p][p] - t * apq;
+                a[q][q] = a[q][q] + t * apq;
+                a[p][q] = T::zero();
+                a[q][p] = T::zero();
+                for r in 0..N {
+                    if r != p && r != q {
+                        let (arp, arq) = (a[r][p], a[r][q]);
+                        a[r][p] = c * arp - s * arq;
+                        a[p][r] = a[r][p];
+                        a[r][q] = s * arp + c * arq;
+                        a[q][r] = a[r][q];
+                    }
+                    let (vrp, vrq) = (v[r][p], v[r][q]);
+                    v[r][p] = c * vrp - s * vrq;
+                    v[r][q] = s * vrp + c * vrq;
+                }
+            }
+        }
+    }
+    let mut values = [T::zero(); N];
+    for i in 0..N {
+        values[i] = a[i][i];
+    }
+    //selection sort into decreasing order, swapping eigenvector columns to match
+    for i in 0..N {
+        let mut m = i;
+        for j in i + 1..N {
+            if values[j] > values[m] {
+                m = j;
+            }
+        }
+        values.swap(i, m);
+        for row in v.iter_mut() {
+            row.swap(i, m);
+        }
+    }
+    (values, v)
+}
+
+//householder QR, A = Q * R with Q orthogonal and R upper triangular
+pub(crate) fn qr<T: Scalar + Sqrt<T>, const N: usize>(a: [[T; N]; N]) -> ([[T; N]; N], [[T; N]; N]) {
+    let tol = tolerance(&a);
+    let mut q = [[T::zero(); N]; N];
+    for i in 0..N {
+        q[i][i] = T::one();
+    }
+    let mut r = a;
+    for k in 0..N.saturating_sub(1) {
+        let mut v = [T::zero(); N];
+        let mut norm = T::zero();
+        for i in k..N {
+            v[i] = r[i][k];
+            norm = norm + v[i] * v[i];
+        }
+        let norm = norm.sqrt();
+        if norm <= tol {
+            continue;
+        }
+        //reflect onto -sign(x0) * |x| * e_k so the subtraction below never cancels
+        let alpha = if v[k] < T::zero() { norm } else { -norm };
+        v[k] = v[k] - alpha;
+        let mut vv = T::zero();
+        for i in k..N {
+            vv = vv + v[i] * v[i];
+        }
+        let f = (T::one() + T::one()) / vv;
+        //R = H * R
+        for j in 0..N {
+            let mut d = T::zero();
+            for i in k..N {
+                d = d + v[i] * r[i][j];
+            }
+            for i in k..N {
+                r[i][j] = r[i][j] - f * d * v[i];
+            }
+        }
+        //Q = Q * H
+        for row in q.iter_mut() {
+            let mut d = T::zero();
+            for i in k..N {
+                d = d + row[i] * v[i];
+            }
+            for i in k..N {
+                row[i] = row[i] - f * d * v[i];
+            }
+        }
+        for row in r.iter_mut().skip(k + 1) {
+            row[k] = T::zero();
+        }
+    }
+    (q, r)
+}
+
+//minimizes |A * x - b| over the rows of A without forming A^T * A
+//each row is folded into a running triangular factor with givens rotations, so any number of rows can be used
+pub(crate) fn least_squares<T: Scalar + Sqrt<T> + ExactDiv, const N: usize>(rows: impl Iterator<Item=([T; N], T)>) -> Option<[T; N]> {
+    let mut r = [[T::zero(); N]; N];
+    let mut z = [T::zero(); N];
+    for (mut a, mut b) in rows {
+        for k in 0..N {
+            if a[k] == T::zero() {
+                continue;
+            }
+            let h = (r[k][k] * r[k][k] + a[k] * a[k]).sqrt();
+            let (c, s) = (r[k][k] / h, a[k] / h);
+            for j in k..N {
+                let (rkj, aj) = (r[k][j], a[j]);
+                r[k][j] = c * rkj + s * aj;
+                a[j] = c * aj - s * rkj;
+            }
+            let zk = z[k];
+            z[k] = c * zk + s * b;
+            b = c * b - s * zk;
+        }
+    }
+    let mut max = T::zero();
+    for k in 0..N {
+        max = max.non_nan_max(r[k][k].abs());
+    }
+    let tol = count::<T>(N) * max * T::epsilon();
+    for k in 0..N {
+        if r[k][k].abs() <= tol || r[k][k].is_nan() {
+            return None;
+        }
+    }
+    back_sub(&r, z)
+}
+
+#[macro_export]
+macro_rules! impl_linalg {
+($mat: ident, $vec: ident, $n: literal) => {
+    //for integers every division has to be exact, anything that would need a fraction is None instead of being truncated
+    impl<T: Scalar + ExactDiv> $mat<T> {
+        //None only for integer matrices that have no LU factorization with integer multipliers
+        pub fn lu(self) -> Option<$crate::linalg::Lu<$mat<T>, $n>> {
+            let $crate::linalg::Lu { l, u, perm, rank, odd } = $crate::linalg::lu::<T, $n>(self.into())?;
+            Some($crate::linalg::Lu { l: l.into(), u: u.into(), perm, rank, odd })
+        }
+        
+        //None if the matrix is rank deficient, or for integers if the solution isn't integral
+        pub fn solve(self, b: $vec<T>) -> Option<$vec<T>> {
+            match self.lu() {
+                Some(lu) => lu.solve(b),
+                None => $crate::linalg::bareiss_solve::<T, $n>(self.into(), b.into()).map(|x| x.into()),
+            }
+        }
+        
+        //lower triangular L with A = L * L^T, None if A is not symmetric positive definite
+        pub fn cholesky(self) -> Option<Self>
+            where T: Float {
+            $crate::linalg::cholesky::<T, $n>(self.into()).map(|l| l.into())
+        }
+        
+        //unit lower triangular L and diagonal D with A = L * D * L^T
+        pub fn ldlt(self) -> Option<(Self, $vec<T>)> {
+            $crate::linalg::ldlt::<T, $n>(self.into()).map(|(l, d)| (l.into(), d.into()))
+        }
+        
+        pub fn cholesky_solve(self, b: $vec<T>) -> Option<$vec<T>>
+            where T: Float {
+            let l = $crate::linalg::cholesky::<T, $n>(self.into())?;
+            let y = $crate::linalg::forward_sub(&l, b.into())?;
+            $crate::linalg::back_sub(&$crate::linalg::transpose(l), y).map(|x| x.into())
+        }
+        
+        //(Q, R) with Q orthogonal and R upper triangular
+        pub fn qr(self) -> (Self, Self)
+            where T: Float {
+            let (q, r) = $crate::linalg::qr::<T, $n>(self.into());
+            (q.into(), r.into())
+        }
+        
+        //best fit x for dot(rows[i], x) = targets[i]
+        //None if the rows don't span every dimension (including no rows at all) or there isn't one target per row
+        pub fn least_squares(rows: &[$vec<T>], targets: &[T]) -> Option<$vec<T>>
+            where T: Float {
+            if rows.len() != targets.len() {
+                return None;
+            }
+            let rows = rows.iter().zip(targets).map(|(&a, &b)| (a.into(), b));
+            $crate::linalg::least_squares::<T, $n>(rows).map(|x| x.into())
+        }
+    }
+    
+    impl<T: Scalar + ExactDiv> $crate::linalg::Lu<$mat<T>, $n> {
+        //None if rank < N, or for integers if the solution isn't integral
+        pub fn solve(&self, b: $vec<T>) -> Option<$vec<T>> {
+            if self.rank < $n {
+                return None;
+            }
+            let b: [T; $n] = b.into();
+            let mut pb = b;
+            for (i, &p) in self.perm.iter().enumerate() {
+                pb[i] = b[p];
+            }
+            let y = $crate::linalg::forward_sub(&self.l.into(), pb)?;
+            $crate::linalg::back_sub(&self.u.into(), y).map(|x| x.into())
+        }
+        
+        pub fn det(&self) -> T {
+            let u: [[T; $n]; $n] = self.u.into();
+            let mut r = if self.odd { -T::one() } else { T::one() };
+            for (i, row) in u.iter().enumerate() {
+                r = r * row[i];
+            }
+            r
+        }
+    }
+}}
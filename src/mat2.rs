@@ -1,5 +1,6 @@
 use crate::prelude::*;
 use crate::vec2::*;
+use crate::error::*;
 
 //TODO: generalize everything to a trait the same way as vectors?
 #[repr(C)]
@@ -13,6 +14,19 @@ pub fn mat2<T>(x: Vec2<T>, y: Vec2<T>) -> Mat2<T> {
     Mat2 { x, y, }
 }
 
+impl<T> From<Mat2<T>> for [[T; 2]; 2] {
+    fn from(m: Mat2<T>) -> Self {
+        [m.x.into(),m.y.into()]
+    }
+}
+impl<T> From<[[T; 2]; 2]> for Mat2<T> {
+    fn from([x,y]: [[T; 2]; 2]) -> Self {
+        mat2(x.into(),y.into())
+    }
+}
+
+impl_linalg!(Mat2, Vec2, 2);
+
 //TODO: macro-ify all this like VecN?
 impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> Mat2<T>
     where Vec2<T>: VecOps<T> {
@@ -28,14 +42,37 @@ impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<
         x.x * y.y - x.y * y.x
     }
     
-    pub fn inv(self) -> Self {
+    pub fn cofactor(self) -> Self {
         let Self{ x,y } = self;
         mat2(
-            vec2(y.y, -y.x) / self.det(),
-            vec2(-x.y, x.x) / self.det(),
+            vec2(y.y, -y.x),
+            vec2(-x.y, x.x),
         )
     }
     
+    pub fn adjoint(self) -> Self {
+        self.cofactor().transpose()
+    }
+    
+    pub fn inv(self) -> Self {
+        let Self{ x,y } = self.adjoint();
+        let det = self.det();
+        mat2(x/det,y/det)
+    }
+    
+    pub fn try_inv(self, epsilon: T) -> Result<Self, InvError<T>>
+        where T: PartialOrd + Abs + ExactDiv {
+        let det = self.det();
+        if det.abs() <= epsilon {
+            return Err(InvError::Singular { det });
+        }
+        let Self{ x,y } = self.adjoint();
+        match (x.exact_div(det), y.exact_div(det)) {
+            (Some(x), Some(y)) => Ok(mat2(x,y)),
+            _ => Err(InvError::NonIntegral { det }),
+        }
+    }
+    
     pub fn apply_to(self, v: Vec2<T>) -> Vec2<T> {
         vec2(
             dot(self.x, v),
@@ -59,6 +96,49 @@ impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<
     }
 }
 
+impl<T: Scalar + Sqrt<T>> Mat2<T> {
+    //closed form version of Mat3::symmetric_eigen
+    pub fn symmetric_eigen(self) -> (Vec2<T>, Mat2<T>) {
+        let (a, b, d) = (self.x.x, self.x.y, self.y.y);
+        let two = T::one() + T::one();
+        let (mean, half) = ((a + d) / two, (a - d) / two);
+        let r = (half * half + b * b).sqrt();
+        let (l1, l2) = (mean + r, mean - r);
+        //either row of (A - l1 * I) gives an eigenvector, take the better conditioned one
+        let (u, v) = (vec2(b, l1 - a), vec2(l1 - d, b));
+        let (nu, nv) = (u.x * u.x + u.y * u.y, v.x * v.x + v.y * v.y);
+        let (e, n) = if nu > nv { (u, nu) } else { (v, nv) };
+        let e = if n <= (a * a + d * d + b * b) * T::epsilon() * T::epsilon() {
+            vec2(T::one(), T::zero())
+        } else {
+            e / n.sqrt()
+        };
+        (vec2(l1, l2), mat2(vec2(e.x, -e.y), vec2(e.y, e.x)))
+    }
+    
+    //returns (U, S, V^T) with self = U * diag(S) * V^T, where U and V are both proper rotations
+    //reflections (det < 0) are carried by making the second singular value negative
+    pub fn svd(self) -> (Mat2<T>, Vec2<T>, Mat2<T>)
+        where T: Trig {
+        let Self{ x,y } = self;
+        let two = T::one() + T::one();
+        let (e, f) = ((x.x + y.y) / two, (x.x - y.y) / two);
+        let (g, h) = ((y.x + x.y) / two, (y.x - x.y) / two);
+        let (q, r) = ((e * e + h * h).sqrt(), (f * f + g * g).sqrt());
+        let (a1, a2) = (g.atan2(f), h.atan2(e));
+        let (theta, phi) = ((a2 - a1) / two, (a2 + a1) / two);
+        (Mat2::rotate(phi), vec2(q + r, q - r), Mat2::rotate(theta))
+    }
+    
+    //returns (R, S) with self = R * S, R a proper rotation and S symmetric
+    pub fn polar(self) -> (Mat2<T>, Mat2<T>)
+        where T: Trig {
+        let (u, s, vt) = self.svd();
+        let v = vt.transpose();
+        (u * vt, v * mat2(vec2(s.x, T::zero()), vec2(T::zero(), s.y)) * vt)
+    }
+}
+
 impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> Default for Mat2<T>
     where Vec2<T>: VecOps<T> {
     fn default() -> Self {
@@ -4,6 +4,8 @@ use crate::vec3::*;
 use crate::vec4::*;
 use crate::mat2::*;
 use crate::mat4::*;
+use crate::error::*;
+use crate::euler::*;
 
 #[repr(C)]
 #[cfg_attr(feature="serde", derive(Serialize,Deserialize))]
@@ -17,6 +19,19 @@ pub fn mat3<T>(x: Vec3<T>, y: Vec3<T>, z: Vec3<T>) -> Mat3<T> {
     Mat3 { x, y, z, }
 }
 
+impl<T> From<Mat3<T>> for [[T; 3]; 3] {
+    fn from(m: Mat3<T>) -> Self {
+        [m.x.into(),m.y.into(),m.z.into()]
+    }
+}
+impl<T> From<[[T; 3]; 3]> for Mat3<T> {
+    fn from([x,y,z]: [[T; 3]; 3]) -> Self {
+        mat3(x.into(),y.into(),z.into())
+    }
+}
+
+impl_linalg!(Mat3, Vec3, 3);
+
 impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> Mat3<T>
     where Vec3<T>: VecOps<T>, Vec2<T>: VecOps<T> {
     pub fn ident() -> Self {
@@ -72,6 +87,19 @@ impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<
         mat3(x/self.det(),y/self.det(),z/self.det())
     }
     
+    pub fn try_inv(self, epsilon: T) -> Result<Self, InvError<T>>
+        where T: PartialOrd + Abs + ExactDiv {
+        let det = self.det();
+        if det.abs() <= epsilon {
+            return Err(InvError::Singular { det });
+        }
+        let Mat3{ x,y,z } = self.adjoint();
+        match (x.exact_div(det), y.exact_div(det), z.exact_div(det)) {
+            (Some(x), Some(y), Some(z)) => Ok(mat3(x,y,z)),
+            _ => Err(InvError::NonIntegral { det }),
+        }
+    }
+    
     pub fn apply_to(self, v: Vec3<T>) -> Vec3<T> {
         vec3(
             dot(self.x, v),
@@ -89,7 +117,72 @@ impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<
         )
     }
 }
+
+//2d transforms in homogeneous coordinates, points are extended with 1 and vectors with 0 so only points are translated
+//the then_* helpers apply another transform after self, e.g. Mat3::from_scale_2d(s).then_rotate_2d(a).then_translate_2d(t)
+impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> Mat3<T>
+    where Vec3<T>: VecOps<T>, Vec2<T>: VecOps<T> {
+    //linear part m followed by translation t
+    pub fn from_mat2_2d(m: Mat2<T>, t: Vec2<T>) -> Self {
+        mat3(
+            m.x.extend(t.x),
+            m.y.extend(t.y),
+            vec3(T::zero(), T::zero(), T::one()),
+        )
+    }
+    
+    pub fn from_translation_2d(t: Vec2<T>) -> Self {
+        Self::from_mat2_2d(Mat2::ident(), t)
+    }
+    
+    pub fn from_angle_2d(angle: T) -> Self
+        where T: Trig {
+        Self::from_mat2_2d(Mat2::rotate(angle), Vec2::zero())
+    }
+    
+    pub fn from_scale_2d(s: Vec2<T>) -> Self {
+        Self::from_mat2_2d(mat2(vec2(s.x, T::zero()), vec2(T::zero(), s.y)), Vec2::zero())
+    }
     
+    //x += shear.x * y and y += shear.y * x
+    pub fn from_shear_2d(shear: Vec2<T>) -> Self {
+        Self::from_mat2_2d(mat2(vec2(T::one(), shear.x), vec2(shear.y, T::one())), Vec2::zero())
+    }
+    
+    //scales, then rotates, then translates
+    pub fn from_trs_2d(t: Vec2<T>, angle: T, s: Vec2<T>) -> Self
+        where T: Trig {
+        let r = Mat2::rotate(angle);
+        Self::from_mat2_2d(mat2(vec2(r.x.x * s.x, r.x.y * s.y), vec2(r.y.x * s.x, r.y.y * s.y)), t)
+    }
+    
+    pub fn then_translate_2d(self, t: Vec2<T>) -> Self {
+        Self::from_translation_2d(t) * self
+    }
+    
+    pub fn then_rotate_2d(self, angle: T) -> Self
+        where T: Trig {
+        Self::from_angle_2d(angle) * self
+    }
+    
+    pub fn then_scale_2d(self, s: Vec2<T>) -> Self {
+        Self::from_scale_2d(s) * self
+    }
+    
+    pub fn then_shear_2d(self, shear: Vec2<T>) -> Self {
+        Self::from_shear_2d(shear) * self
+    }
+    
+    //the bottom row is assumed to be (0, 0, 1), there is no divide by w
+    pub fn transform_point2(self, p: Vec2<T>) -> Vec2<T> {
+        self.apply_to(p.extend(T::one())).downsize()
+    }
+    
+    pub fn transform_vector2(self, v: Vec2<T>) -> Vec2<T> {
+        self.apply_to(v.extend(T::zero())).downsize()
+    }
+}
+
 impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T> + Trig> Mat3<T> {
     pub fn rotate_x(angle: T) -> Self {
         mat3(
@@ -116,6 +209,111 @@ impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<
     }
 }
 
+impl<T: Scalar + Trig + Sqrt<T>> Mat3<T> {
+    fn rotate_axis(axis: usize, angle: T) -> Self {
+        match axis {
+            0 => Self::rotate_x(angle),
+            1 => Self::rotate_y(angle),
+            _ => Self::rotate_z(angle),
+        }
+    }
+    
+    pub fn from_euler(order: EulerOrder, angles: Vec3<T>) -> Self {
+        let (axes, reversed) = order.to_intrinsic();
+        let [i, j, k] = axes.axes();
+        let (a, b, c) = if reversed { (angles.z, angles.y, angles.x) } else { (angles.x, angles.y, angles.z) };
+        Self::rotate_axis(i, a) * Self::rotate_axis(j, b) * Self::rotate_axis(k, c)
+    }
+    
+    //self must be a rotation matrix, the inverse of from_euler with the middle angle in [-pi/2, pi/2] (tait-bryan) or [0, pi] (proper)
+    //at gimbal lock the outer two rotations are about the same axis, so the whole turn goes to the leftmost one in the product and the other is 0
+    pub fn to_euler(self, order: EulerOrder) -> Vec3<T> {
+        let (axes, reversed) = order.to_intrinsic();
+        let m: [[T; 3]; 3] = self.into();
+        let [i, j, _] = axes.axes();
+        let k = 3 - i - j;
+        //+1 when i, j, k is a cyclic permutation of x, y, z
+        let e = if j == (i + 1) % 3 { T::one() } else { -T::one() };
+        let tol = T::epsilon().sqrt();
+        let (a, b, c) = if axes.is_proper() {
+            let sin_b = (m[i][j] * m[i][j] + m[i][k] * m[i][k]).sqrt();
+            let b = sin_b.atan2(m[i][i]);
+            if sin_b > tol {
+                (m[j][i].atan2(-e * m[k][i]), b, m[i][j].atan2(e * m[i][k]))
+            } else {
+                ((e * m[k][j]).atan2(m[j][j]), b, T::zero())
+            }
+        } else {
+            let cos_b = (m[i][i] * m[i][i] + m[i][j] * m[i][j]).sqrt();
+            let b = (e * m[i][k]).atan2(cos_b);
+            if cos_b > tol {
+                ((-e * m[j][k]).atan2(m[k][k]), b, (-e * m[i][j]).atan2(m[i][i]))
+            } else {
+                ((e * m[k][j]).atan2(m[j][j]), b, T::zero())
+            }
+        };
+        if reversed { vec3(c, b, a) } else { vec3(a, b, c) }
+    }
+}
+
+impl<T: Scalar + Sqrt<T>> Mat3<T> {
+    //eigenvalues in decreasing order, with the matching unit eigenvectors as the columns of the matrix
+    //self must be symmetric
+    pub fn symmetric_eigen(self) -> (Vec3<T>, Mat3<T>) {
+        self.symmetric_eigen_capped(32)
+    }
+    
+    pub fn symmetric_eigen_capped(self, max_sweeps: usize) -> (Vec3<T>, Mat3<T>) {
+        let (values, vectors) = crate::linalg::jacobi_eigen::<T, 3>(self.into(), max_sweeps);
+        (values.into(), vectors.into())
+    }
+    
+    //returns (U, S, V^T) with self = U * diag(S) * V^T, where U and V are both proper rotations
+    //reflections (det < 0) are carried by making the smallest singular value negative
+    pub fn svd(self) -> (Mat3<T>, Vec3<T>, Mat3<T>) {
+        let (_, v) = (self.transpose() * self).symmetric_eigen();
+        let v = if v.det() < T::zero() {
+            mat3(vec3(v.x.x, v.x.y, -v.x.z), vec3(v.y.x, v.y.y, -v.y.z), vec3(v.z.x, v.z.y, -v.z.z))
+        } else {
+            v
+        };
+        //the columns of A * V are the columns of U scaled by the singular values
+        let b = (self * v).transpose();
+        let tol = (b.x.dot(b.x) + b.y.dot(b.y) + b.z.dot(b.z)) * T::epsilon() * T::epsilon();
+        let u0 = if b.x.dot(b.x) > tol { b.x.normalize() } else { vec3(T::one(), T::zero(), T::zero()) };
+        let b1 = b.y - u0 * u0.dot(b.y);
+        let u1 = if b1.dot(b1) > tol {
+            b1.normalize()
+        } else {
+            //any direction perpendicular to u0 will do, build it from the axis u0 is least aligned with
+            let a = u0.abs();
+            let axis = if a.x <= a.y && a.x <= a.z {
+                vec3(T::one(), T::zero(), T::zero())
+            } else if a.y <= a.z {
+                vec3(T::zero(), T::one(), T::zero())
+            } else {
+                vec3(T::zero(), T::zero(), T::one())
+            };
+            cross(u0, axis).normalize()
+        };
+        let u2 = cross(u0, u1);
+        let u = mat3(u0, u1, u2).transpose();
+        (u, vec3(u0.dot(b.x), u1.dot(b.y), u2.dot(b.z)), v.transpose())
+    }
+    
+    //returns (R, S) with self = R * S, R a proper rotation and S symmetric
+    pub fn polar(self) -> (Mat3<T>, Mat3<T>) {
+        let (u, s, vt) = self.svd();
+        let v = vt.transpose();
+        let d = mat3(
+            vec3(s.x, T::zero(), T::zero()),
+            vec3(T::zero(), s.y, T::zero()),
+            vec3(T::zero(), T::zero(), s.z),
+        );
+        (u * vt, v * d * vt)
+    }
+}
+
 impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> Default for Mat3<T>
     where Vec3<T>: VecOps<T>, Vec2<T>: VecOps<T> {
     fn default() -> Self {
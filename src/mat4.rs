@@ -1,5 +1,11 @@
 use crate::prelude::*;
+use crate::vec2::*;
+use crate::vec3::*;
 use crate::vec4::*;
+use crate::mat3::*;
+use crate::error::*;
+use crate::quaternion::*;
+use crate::linalg::tolerance;
 
 #[repr(C)]
 #[cfg_attr(feature="serde", derive(Serialize,Deserialize))]
@@ -14,6 +20,26 @@ pub fn mat4<T>(x: Vec4<T>, y: Vec4<T>, z: Vec4<T>, w: Vec4<T>) -> Mat4<T> {
     Mat4 { x, y, z, w, }
 }
 
+impl<T> From<Mat4<T>> for [[T; 4]; 4] {
+    fn from(m: Mat4<T>) -> Self {
+        [m.x.into(),m.y.into(),m.z.into(),m.w.into()]
+    }
+}
+impl<T> From<[[T; 4]; 4]> for Mat4<T> {
+    fn from([x,y,z,w]: [[T; 4]; 4]) -> Self {
+        mat4(x.into(),y.into(),z.into(),w.into())
+    }
+}
+
+impl_linalg!(Mat4, Vec4, 4);
+
+impl<T> Mat4<T> {
+    //the upper left 3x3, the inverse of Mat3::extend
+    pub fn downsize(self) -> Mat3<T> {
+        mat3(self.x.downsize(), self.y.downsize(), self.z.downsize())
+    }
+}
+
 impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> Mat4<T>
     where Vec4<T>: VecOps<T> {
     pub fn ident() -> Self {
@@ -43,6 +69,48 @@ impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<
         )
     }
     
+    //p is extended with 1 and the result divided by w, so this works for projections as well as affine transforms
+    pub fn transform_point3(self, p: Vec3<T>) -> Vec3<T> {
+        let Vec4{ x,y,z,w } = self.apply_to(p.extend(T::one()));
+        vec3(x / w, y / w, z / w)
+    }
+    
+    //v is extended with 0, so translation is ignored and there is no divide
+    pub fn transform_vector3(self, v: Vec3<T>) -> Vec3<T> {
+        self.apply_to(v.extend(T::zero())).downsize()
+    }
+    
+    //like transform_point3, but None if w isn't positive, i.e. p is on or behind the eye plane of a perspective projection
+    //those points would otherwise come out mirrored through the eye or infinite
+    pub fn project_point3(self, p: Vec3<T>) -> Option<Vec3<T>>
+        where T: PartialOrd {
+        let Vec4{ x,y,z,w } = self.apply_to(p.extend(T::one()));
+        if w > T::zero() { Some(vec3(x / w, y / w, z / w)) } else { None }
+    }
+    
+    //the slice versions panic if input and output have different lengths
+    pub fn transform_point3_slice(self, input: &[Vec3<T>], output: &mut [Vec3<T>]) {
+        assert_eq!(input.len(), output.len(), "transform_point3_slice needs an output for every input");
+        for (o, &p) in output.iter_mut().zip(input) {
+            *o = self.transform_point3(p);
+        }
+    }
+    
+    pub fn transform_vector3_slice(self, input: &[Vec3<T>], output: &mut [Vec3<T>]) {
+        assert_eq!(input.len(), output.len(), "transform_vector3_slice needs an output for every input");
+        for (o, &v) in output.iter_mut().zip(input) {
+            *o = self.transform_vector3(v);
+        }
+    }
+    
+    pub fn project_point3_slice(self, input: &[Vec3<T>], output: &mut [Option<Vec3<T>>])
+        where T: PartialOrd {
+        assert_eq!(input.len(), output.len(), "project_point3_slice needs an output for every input");
+        for (o, &p) in output.iter_mut().zip(input) {
+            *o = self.project_point3(p);
+        }
+    }
+    
     pub fn det(self) -> T {
         let Mat4{ x,y,z,w } = self;
           x.x * y.y * z.z * w.w
@@ -78,71 +146,395 @@ impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<
         - x.w * y.z * z.x * w.y
     }
     
-    pub fn inv(self) -> Self {
+    //adjoint and determinant sharing the 12 2x2 sub-determinants of the top and bottom row pairs
+    fn adjoint_det(self) -> (Self, T) {
         let Mat4{ x,y,z,w } = self;
-        mat4(
+        let s0 = x.x * y.y - y.x * x.y;
+        let s1 = x.x * y.z - y.x * x.z;
+        let s2 = x.x * y.w - y.x * x.w;
+        let s3 = x.y * y.z - y.y * x.z;
+        let s4 = x.y * y.w - y.y * x.w;
+        let s5 = x.z * y.w - y.z * x.w;
+        
+        let c5 = z.z * w.w - w.z * z.w;
+        let c4 = z.y * w.w - w.y * z.w;
+        let c3 = z.y * w.z - w.y * z.z;
+        let c2 = z.x * w.w - w.x * z.w;
+        let c1 = z.x * w.z - w.x * z.z;
+        let c0 = z.x * w.y - w.x * z.y;
+        
+        let det = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+        let adj = mat4(
             vec4(
-                    y.y * z.z * w.w + y.z * z.w * w.y + y.w * z.y * w.z
-                - y.y * z.w * w.z - y.z * z.y * w.w - y.w * z.z * w.y,
-                
-                    x.y * z.w * w.z + x.z * z.y * w.w + x.w * z.z * w.y
-                - x.y * z.z * w.w - x.z * z.w * w.y - x.w * z.y * w.z,
-                
-                    x.y * y.z * w.w + x.z * y.w * w.y + x.w * y.y * w.z
-                - x.y * y.w * w.z - x.z * y.y * w.w - x.w * y.z * w.y,
-                
-                    x.y * y.w * z.z + x.z * y.y * z.w + x.w * y.z * z.y
-                - x.y * y.z * z.w - x.z * y.w * z.y - x.w * y.y * z.z,
-            ) / self.det(),
+                y.y * c5 - y.z * c4 + y.w * c3,
+                -x.y * c5 + x.z * c4 - x.w * c3,
+                w.y * s5 - w.z * s4 + w.w * s3,
+                -z.y * s5 + z.z * s4 - z.w * s3),
             vec4(
-                    y.x * z.w * w.z + y.z * z.x * w.w + y.w * z.z * w.x
-                - y.x * z.z * w.w - y.z * z.w * w.x - y.w * z.x * w.z,
-                
-                    x.x * z.z * w.w + x.z * z.w * w.x + x.w * z.x * w.z
-                - x.x * z.w * w.z - x.z * z.x * w.w - x.w * z.z * w.x,
-                
-                    x.x * y.w * w.z + x.z * y.x * w.w + x.w * y.z * w.x
-                - x.x * y.z * w.w - x.z * y.w * w.x - x.w * y.x * w.z,
-                
-                    x.x * y.z * z.w + x.z * y.w * z.x + x.w * y.x * z.z
-                - x.x * y.w * z.z - x.z * y.x * z.w - x.w * y.z * z.x,
-            ) / self.det(),
+                -y.x * c5 + y.z * c2 - y.w * c1,
+                x.x * c5 - x.z * c2 + x.w * c1,
+                -w.x * s5 + w.z * s2 - w.w * s1,
+                z.x * s5 - z.z * s2 + z.w * s1),
             vec4(
-                    y.x * z.y * w.w + y.y * z.w * w.x + y.w * z.x * w.y
-                - y.x * z.w * w.y - y.y * z.x * w.w - y.w * z.y * w.x,
-                
-                    x.x * z.w * w.y + x.y * z.x * w.w + x.w * z.y * w.x
-                - x.x * z.y * w.w - x.y * z.w * w.x - x.w * z.x * w.y,
-                
-                    x.x * y.y * w.w + x.y * y.w * w.x + x.w * y.x * w.y
-                - x.x * y.w * w.y - x.y * y.x * w.w - x.w * y.y * w.x,
-                
-                    x.x * y.w * z.y + x.y * y.x * z.w + x.w * y.y * z.x
-                - x.x * y.y * z.w - x.y * y.w * z.x - x.w * y.x * z.y,
-            ) / self.det(),
+                y.x * c4 - y.y * c2 + y.w * c0,
+                -x.x * c4 + x.y * c2 - x.w * c0,
+                w.x * s4 - w.y * s2 + w.w * s0,
+                -z.x * s4 + z.y * s2 - z.w * s0),
             vec4(
-                    y.x * z.z * w.y + y.y * z.x * w.z + y.z * z.y * w.x
-                - y.x * z.y * w.z - y.y * z.z * w.x - y.z * z.x * w.y,
-                
-                    x.x * z.y * w.z + x.y * z.z * w.x + x.z * z.x * w.y
-                - x.x * z.z * w.y - x.y * z.x * w.z - x.z * z.y * w.x,
-                
-                    x.x * y.z * w.y + x.y * y.x * w.z + x.z * y.y * w.x
-                - x.x * y.y * w.z - x.y * y.z * w.x - x.z * y.x * w.y,
-                
-                    x.x * y.y * z.z + x.y * y.z * z.x + x.z * y.x * z.y
-                - x.x * y.z * z.y - x.y * y.x * z.z - x.z * y.y * z.x,
-            ) / self.det(),
-        )
+                -y.x * c3 + y.y * c1 - y.z * c0,
+                x.x * c3 - x.y * c1 + x.z * c0,
+                -w.x * s3 + w.y * s1 - w.z * s0,
+                z.x * s3 - z.y * s1 + z.z * s0),
+        );
+        (adj, det)
+    }
+    
+    pub fn inv(self) -> Self {
+        let (Mat4{ x,y,z,w }, det) = self.adjoint_det();
+        mat4(x/det,y/det,z/det,w/det)
     }
     
+    pub fn try_inv(self, epsilon: T) -> Result<Self, InvError<T>>
+        where T: PartialOrd + Abs + ExactDiv {
+        let (Mat4{ x,y,z,w }, det) = self.adjoint_det();
+        if det.abs() <= epsilon {
+            return Err(InvError::Singular { det });
+        }
+        match (x.exact_div(det), y.exact_div(det), z.exact_div(det), w.exact_div(det)) {
+            (Some(x), Some(y), Some(z), Some(w)) => Ok(mat4(x,y,z,w)),
+            _ => Err(InvError::NonIntegral { det }),
+        }
+    }
+}
+
+impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> Mat4<T>
+    where Vec4<T>: VecOps<T>, Vec3<T>: VecOps<T>, Vec2<T>: VecOps<T> {
     pub fn cofactor(self) -> Self {
-        todo!()
+        let Mat4{ x,y,z,w } = self;
+        mat4(
+            vec4(
+                mat3(vec3(y.y,y.z,y.w),vec3(z.y,z.z,z.w),vec3(w.y,w.z,w.w)).det(),
+                -mat3(vec3(y.x,y.z,y.w),vec3(z.x,z.z,z.w),vec3(w.x,w.z,w.w)).det(),
+                mat3(vec3(y.x,y.y,y.w),vec3(z.x,z.y,z.w),vec3(w.x,w.y,w.w)).det(),
+                -mat3(vec3(y.x,y.y,y.z),vec3(z.x,z.y,z.z),vec3(w.x,w.y,w.z)).det()),
+            vec4(
+                -mat3(vec3(x.y,x.z,x.w),vec3(z.y,z.z,z.w),vec3(w.y,w.z,w.w)).det(),
+                mat3(vec3(x.x,x.z,x.w),vec3(z.x,z.z,z.w),vec3(w.x,w.z,w.w)).det(),
+                -mat3(vec3(x.x,x.y,x.w),vec3(z.x,z.y,z.w),vec3(w.x,w.y,w.w)).det(),
+                mat3(vec3(x.x,x.y,x.z),vec3(z.x,z.y,z.z),vec3(w.x,w.y,w.z)).det()),
+            vec4(
+                mat3(vec3(x.y,x.z,x.w),vec3(y.y,y.z,y.w),vec3(w.y,w.z,w.w)).det(),
+                -mat3(vec3(x.x,x.z,x.w),vec3(y.x,y.z,y.w),vec3(w.x,w.z,w.w)).det(),
+                mat3(vec3(x.x,x.y,x.w),vec3(y.x,y.y,y.w),vec3(w.x,w.y,w.w)).det(),
+                -mat3(vec3(x.x,x.y,x.z),vec3(y.x,y.y,y.z),vec3(w.x,w.y,w.z)).det()),
+            vec4(
+                -mat3(vec3(x.y,x.z,x.w),vec3(y.y,y.z,y.w),vec3(z.y,z.z,z.w)).det(),
+                mat3(vec3(x.x,x.z,x.w),vec3(y.x,y.z,y.w),vec3(z.x,z.z,z.w)).det(),
+                -mat3(vec3(x.x,x.y,x.w),vec3(y.x,y.y,y.w),vec3(z.x,z.y,z.w)).det(),
+                mat3(vec3(x.x,x.y,x.z),vec3(y.x,y.y,y.z),vec3(z.x,z.y,z.z)).det()),
+        )
     }
     
     pub fn adjoint(self) -> Self {
         self.cofactor().transpose()
     }
+    
+    //only valid when the bottom row is (0, 0, 0, 1), which isn't checked: any other bottom row is treated as if it were
+    pub fn inv_affine(self) -> Self {
+        let a = mat3(self.x.downsize(), self.y.downsize(), self.z.downsize());
+        let Mat3{ x,y,z } = a.adjoint();
+        let det = a.det();
+        let a = mat3(x/det,y/det,z/det);
+        let t = vec3(self.x.w, self.y.w, self.z.w);
+        a.extend(-a.apply_to(t), vec4(T::zero(), T::zero(), T::zero(), T::one()))
+    }
+}
+
+//the depth range of clip space after the divide by w, opengl uses NegOneToOne while d3d, vulkan, metal and wgpu use ZeroToOne
+#[cfg_attr(feature="serde", derive(Serialize,Deserialize))]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum ClipDepth {
+    NegOneToOne,
+    ZeroToOne,
+}
+
+//like everything else these are row major and transform column vectors, so translations are in the w column
+//rh means the camera looks down -z (opengl style), lh means it looks down +z (d3d style)
+impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Sub<Output=T>> Mat4<T> {
+    pub fn from_translation(t: Vec3<T>) -> Self {
+        mat4(
+            vec4(T::one(), T::zero(), T::zero(), t.x),
+            vec4(T::zero(), T::one(), T::zero(), t.y),
+            vec4(T::zero(), T::zero(), T::one(), t.z),
+            vec4(T::zero(), T::zero(), T::zero(), T::one()),
+        )
+    }
+    
+    pub fn from_scale(s: Vec3<T>) -> Self {
+        mat4(
+            vec4(s.x, T::zero(), T::zero(), T::zero()),
+            vec4(T::zero(), s.y, T::zero(), T::zero()),
+            vec4(T::zero(), T::zero(), s.z, T::zero()),
+            vec4(T::zero(), T::zero(), T::zero(), T::one()),
+        )
+    }
+    
+    //q must be normalized
+    pub fn from_quaternion(q: Quaternion<T>) -> Self
+        where T: Two {
+        Self::from_trs(Vec3::zero(), q, Vec3::one())
+    }
+    
+    //from_translation(t) * from_quaternion(r) * from_scale(s), so points are scaled, then rotated, then translated
+    pub fn from_trs(t: Vec3<T>, r: Quaternion<T>, s: Vec3<T>) -> Self
+        where T: Two {
+        let Mat3{ x,y,z } = r.rot_mat();
+        mat4(
+            vec4(x.x * s.x, x.y * s.y, x.z * s.z, t.x),
+            vec4(y.x * s.x, y.y * s.y, y.z * s.z, t.y),
+            vec4(z.x * s.x, z.y * s.y, z.z * s.z, t.z),
+            vec4(T::zero(), T::zero(), T::zero(), T::one()),
+        )
+    }
+    
+    //the inverse of to_scale_rotation_translation, the same as from_trs with the arguments the other way around
+    pub fn from_scale_rotation_translation(scale: Vec3<T>, rotation: Quaternion<T>, translation: Vec3<T>) -> Self
+        where T: Two {
+        Self::from_trs(translation, rotation, scale)
+    }
+}
+
+impl<T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T> + Trig + Sqrt<T> + Abs> Mat4<T> {
+    //the inverse transpose of the upper 3x3, which keeps normals perpendicular to surfaces under non-uniform scale and shear
+    //worth computing once when transforming many normals
+    pub fn normal_matrix(self) -> Mat3<T> {
+        self.downsize().inv().transpose()
+    }
+    
+    //the result is normalized, since the normal matrix generally changes lengths
+    pub fn transform_normal(self, n: Vec3<T>) -> Vec3<T> {
+        self.normal_matrix().apply_to(n).normalize()
+    }
+    
+    //panics if input and output have different lengths
+    pub fn transform_normal_slice(self, input: &[Vec3<T>], output: &mut [Vec3<T>]) {
+        assert_eq!(input.len(), output.len(), "transform_normal_slice needs an output for every input");
+        let m = self.normal_matrix();
+        for (o, &n) in output.iter_mut().zip(input) {
+            *o = m.apply_to(n).normalize();
+        }
+    }
+    
+    //view matrix for a camera at eye looking at target
+    pub fn look_at_rh(eye: Vec3<T>, target: Vec3<T>, up: Vec3<T>) -> Self {
+        let f = (target - eye).normalize();
+        let s = cross(f, up).normalize();
+        let u = cross(s, f);
+        mat4(
+            vec4(s.x, s.y, s.z, -dot(s, eye)),
+            vec4(u.x, u.y, u.z, -dot(u, eye)),
+            vec4(-f.x, -f.y, -f.z, dot(f, eye)),
+            vec4(T::zero(), T::zero(), T::zero(), T::one()),
+        )
+    }
+    
+    pub fn look_at_lh(eye: Vec3<T>, target: Vec3<T>, up: Vec3<T>) -> Self {
+        let f = (target - eye).normalize();
+        let s = cross(up, f).normalize();
+        let u = cross(f, s);
+        mat4(
+            vec4(s.x, s.y, s.z, -dot(s, eye)),
+            vec4(u.x, u.y, u.z, -dot(u, eye)),
+            vec4(f.x, f.y, f.z, -dot(f, eye)),
+            vec4(T::zero(), T::zero(), T::zero(), T::one()),
+        )
+    }
+    
+    //fov_y is the full vertical field of view in radians, aspect is width / height
+    //near and far are positive distances, near maps to the low end of depth and far to the high end
+    pub fn perspective_rh(fov_y: T, aspect: T, near: T, far: T, depth: ClipDepth) -> Self {
+        let (zz, zw) = match depth {
+            ClipDepth::NegOneToOne => ((far + near) / (near - far), T::two() * far * near / (near - far)),
+            ClipDepth::ZeroToOne => (far / (near - far), far * near / (near - far)),
+        };
+        Self::perspective(fov_y, aspect, zz, zw, -T::one())
+    }
+    
+    pub fn perspective_lh(fov_y: T, aspect: T, near: T, far: T, depth: ClipDepth) -> Self {
+        let (zz, zw) = match depth {
+            ClipDepth::NegOneToOne => ((far + near) / (far - near), -T::two() * far * near / (far - near)),
+            ClipDepth::ZeroToOne => (far / (far - near), -far * near / (far - near)),
+        };
+        Self::perspective(fov_y, aspect, zz, zw, T::one())
+    }
+    
+    //the limit of perspective_rh as far goes to infinity
+    pub fn perspective_infinite_rh(fov_y: T, aspect: T, near: T, depth: ClipDepth) -> Self {
+        let zw = match depth {
+            ClipDepth::NegOneToOne => -T::two() * near,
+            ClipDepth::ZeroToOne => -near,
+        };
+        Self::perspective(fov_y, aspect, -T::one(), zw, -T::one())
+    }
+    
+    pub fn perspective_infinite_lh(fov_y: T, aspect: T, near: T, depth: ClipDepth) -> Self {
+        let zw = match depth {
+            ClipDepth::NegOneToOne => -T::two() * near,
+            ClipDepth::ZeroToOne => -near,
+        };
+        Self::perspective(fov_y, aspect, T::one(), zw, T::one())
+    }
+    
+    //reversed z maps near to 1 and far to 0, which spreads float precision much more evenly over the depth range
+    //only ZeroToOne depth makes sense for this, so there is no depth parameter
+    pub fn perspective_reversed_rh(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        Self::perspective(fov_y, aspect, near / (far - near), far * near / (far - near), -T::one())
+    }
+    
+    pub fn perspective_reversed_lh(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        Self::perspective(fov_y, aspect, -near / (far - near), far * near / (far - near), T::one())
+    }
+    
+    pub fn perspective_infinite_reversed_rh(fov_y: T, aspect: T, near: T) -> Self {
+        Self::perspective(fov_y, aspect, T::zero(), near, -T::one())
+    }
+    
+    pub fn perspective_infinite_reversed_lh(fov_y: T, aspect: T, near: T) -> Self {
+        Self::perspective(fov_y, aspect, T::zero(), near, T::one())
+    }
+    
+    //zz and zw are the depth row, ww is where -z or +z is copied into w
+    fn perspective(fov_y: T, aspect: T, zz: T, zw: T, ww: T) -> Self {
+        let f = T::one() / (fov_y / T::two()).tan();
+        mat4(
+            vec4(f / aspect, T::zero(), T::zero(), T::zero()),
+            vec4(T::zero(), f, T::zero(), T::zero()),
+            vec4(T::zero(), T::zero(), zz, zw),
+            vec4(T::zero(), T::zero(), ww, T::zero()),
+        )
+    }
+    
+    //maps the box from (left, bottom, -near) to (right, top, -far) onto clip space
+    pub fn orthographic_rh(left: T, right: T, bottom: T, top: T, near: T, far: T, depth: ClipDepth) -> Self {
+        let (zz, zw) = match depth {
+            ClipDepth::NegOneToOne => (-T::two() / (far - near), -(far + near) / (far - near)),
+            ClipDepth::ZeroToOne => (-T::one() / (far - near), -near / (far - near)),
+        };
+        Self::orthographic(left, right, bottom, top, zz, zw)
+    }
+    
+    //maps the box from (left, bottom, near) to (right, top, far) onto clip space
+    pub fn orthographic_lh(left: T, right: T, bottom: T, top: T, near: T, far: T, depth: ClipDepth) -> Self {
+        let (zz, zw) = match depth {
+            ClipDepth::NegOneToOne => (T::two() / (far - near), -(far + near) / (far - near)),
+            ClipDepth::ZeroToOne => (T::one() / (far - near), -near / (far - near)),
+        };
+        Self::orthographic(left, right, bottom, top, zz, zw)
+    }
+    
+    fn orthographic(left: T, right: T, bottom: T, top: T, zz: T, zw: T) -> Self {
+        mat4(
+            vec4(T::two() / (right - left), T::zero(), T::zero(), -(right + left) / (right - left)),
+            vec4(T::zero(), T::two() / (top - bottom), T::zero(), -(top + bottom) / (top - bottom)),
+            vec4(T::zero(), T::zero(), zz, zw),
+            vec4(T::zero(), T::zero(), T::zero(), T::one()),
+        )
+    }
+}
+
+//the parts of a matrix found by decompose, compose puts them back together as perspective * translation * rotation * shear * scale
+//shear is (xy, xz, yz): how much of the x axis is added to y, of x to z and of y to z, after scaling
+//perspective is the bottom row of the matrix, (0, 0, 0, 1) when there is none
+#[cfg_attr(feature="serde", derive(Serialize,Deserialize))]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct Decomposition<T> {
+    pub scale: Vec3<T>,
+    pub shear: Vec3<T>,
+    pub rotation: Quaternion<T>,
+    pub translation: Vec3<T>,
+    pub perspective: Vec4<T>,
+}
+
+impl<T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T> + Trig + Sqrt<T> + Abs + PartialOrd> Mat4<T> {
+    //self must be affine (the bottom row is ignored) with an invertible upper 3x3
+    //any shear is dropped, use decompose to keep it
+    //a negative determinant (a mirror) is returned as a negative scale.x
+    pub fn to_scale_rotation_translation(self) -> (Vec3<T>, Quaternion<T>, Vec3<T>) {
+        let (scale, _, rotation) = Self::decompose_linear(self.downsize());
+        (scale, rotation, vec3(self.x.w, self.y.w, self.z.w))
+    }
+    
+    //spencer thomas' unmatrix from graphics gems ii, None if self.w.w is 0 or the upper 3x3 is singular (up to rounding)
+    //self is divided by self.w.w first, so compose gives back the same transform but not always the same matrix
+    pub fn decompose(self) -> Option<Decomposition<T>>
+        where T: Epsilon {
+        if self.w.w == T::zero() {
+            return None;
+        }
+        let Mat4{ x,y,z,w } = self;
+        let m = mat4(x / w.w, y / w.w, z / w.w, w / w.w);
+        //m = perspective * affine, where affine is m without the bottom row
+        let affine = mat4(m.x, m.y, m.z, vec4(T::zero(), T::zero(), T::zero(), T::one()));
+        //the determinant is a product of three pivots, so scale linalg's pivot tolerance by max|a| for the other two
+        let rows: [[T; 3]; 3] = m.downsize().into();
+        let max = rows.iter().flatten().fold(T::zero(), |a, &b| a.non_nan_max(b.abs()));
+        if affine.det().abs() <= tolerance(&rows) * max * max {
+            return None;
+        }
+        //the bottom row of m is perspective * affine, so perspective is that times the inverse of affine
+        let perspective = affine.inv_affine().transpose().apply_to(m.w);
+        let (scale, shear, rotation) = Self::decompose_linear(m.downsize());
+        Some(Decomposition { scale, shear, rotation, translation: vec3(m.x.w, m.y.w, m.z.w), perspective })
+    }
+    
+    //gram-schmidt on the columns, which are the images of the axes
+    fn decompose_linear(m: Mat3<T>) -> (Vec3<T>, Vec3<T>, Quaternion<T>) {
+        let c0 = vec3(m.x.x, m.y.x, m.z.x);
+        let c1 = vec3(m.x.y, m.y.y, m.z.y);
+        let c2 = vec3(m.x.z, m.y.z, m.z.z);
+        let sx = c0.magnitude();
+        let r0 = c0 / sx;
+        let xy = dot(r0, c1);
+        let c1 = c1 - r0 * xy;
+        let sy = c1.magnitude();
+        let r1 = c1 / sy;
+        let xz = dot(r0, c2);
+        let yz = dot(r1, c2);
+        let c2 = c2 - r0 * xz - r1 * yz;
+        let sz = c2.magnitude();
+        let r2 = c2 / sz;
+        let (mut scale, mut shear, mut r0) = (vec3(sx, sy, sz), vec3(xy / sy, xz / sz, yz / sz), r0);
+        //r0, r1, r2 are a left handed basis, flip the x axis so they are a rotation again
+        if dot(r0, cross(r1, r2)) < T::zero() {
+            scale.x = -scale.x;
+            shear.x = -shear.x;
+            shear.y = -shear.y;
+            r0 = -r0;
+        }
+        let rot = mat3(vec3(r0.x, r1.x, r2.x), vec3(r0.y, r1.y, r2.y), vec3(r0.z, r1.z, r2.z));
+        (scale, shear, Quaternion::from_rot_mat(rot))
+    }
+}
+
+impl<T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> Decomposition<T> {
+    pub fn compose(self) -> Mat4<T> {
+        let Decomposition { scale: s, shear, rotation, translation: t, perspective } = self;
+        let Mat3{ x,y,z } = rotation.rot_mat();
+        //columns of rotation * shear * scale
+        let r0 = vec3(x.x, y.x, z.x);
+        let r1 = vec3(x.y, y.y, z.y);
+        let r2 = vec3(x.z, y.z, z.z);
+        let c0 = r0 * s.x;
+        let c1 = (r0 * shear.x + r1) * s.y;
+        let c2 = (r0 * shear.y + r1 * shear.z + r2) * s.z;
+        let x = vec4(c0.x, c1.x, c2.x, t.x);
+        let y = vec4(c0.y, c1.y, c2.y, t.y);
+        let z = vec4(c0.z, c1.z, c2.z, t.z);
+        let p = perspective;
+        //the bottom row of perspective * affine
+        let w = x * p.x + y * p.y + z * p.z + vec4(T::zero(), T::zero(), T::zero(), p.w);
+        mat4(x, y, z, w)
+    }
 }
 
 impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T>> Default for Mat4<T>
@@ -0,0 +1,171 @@
+use crate::prelude::*;
+use crate::vec2::*;
+use crate::vec3::*;
+use crate::vec4::*;
+use crate::vecn::*;
+use crate::mat2::*;
+use crate::mat3::*;
+use crate::mat4::*;
+
+//R x C matrix stored as R rows like Mat2/Mat3/Mat4, products only compile when the inner dimensions match
+//serde only implements its traits for arrays up to length 32, so there is no serde derive here
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct Mat<T, const R: usize, const C: usize>(pub [VecN<T, C>; R]);
+
+pub fn matn<T, const R: usize, const C: usize>(rows: [[T; C]; R]) -> Mat<T, R, C> {
+    Mat(rows.map(VecN))
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Mat<T, R, C> {
+    fn from(rows: [[T; C]; R]) -> Self {
+        matn(rows)
+    }
+}
+impl<T, const R: usize, const C: usize> From<Mat<T, R, C>> for [[T; C]; R] {
+    fn from(m: Mat<T, R, C>) -> Self {
+        m.0.map(|r| r.0)
+    }
+}
+
+macro convert_square($($M: ident, $n: literal);*) {
+    $(
+        impl<T> From<$M<T>> for Mat<T, $n, $n> {
+            fn from(m: $M<T>) -> Self {
+                let rows: [[T; $n]; $n] = m.into();
+                rows.into()
+            }
+        }
+        impl<T> From<Mat<T, $n, $n>> for $M<T> {
+            fn from(m: Mat<T, $n, $n>) -> Self {
+                let rows: [[T; $n]; $n] = m.into();
+                rows.into()
+            }
+        }
+    )*
+}
+
+convert_square!(Mat2, 2; Mat3, 3; Mat4, 4);
+
+impl<T: Copy + Zero + One, const N: usize> Mat<T, N, N> {
+    pub fn ident() -> Self {
+        Mat(std::array::from_fn(|i| VecN(std::array::from_fn(|j| if i == j { T::one() } else { T::zero() }))))
+    }
+}
+
+impl<T: Copy, const R: usize, const C: usize> Mat<T, R, C> {
+    pub fn transpose(self) -> Mat<T, C, R> {
+        Mat(std::array::from_fn(|j| VecN(std::array::from_fn(|i| self.0[i].0[j]))))
+    }
+    
+    pub fn row(self, i: usize) -> VecN<T, C> {
+        self.0[i]
+    }
+    
+    pub fn col(self, j: usize) -> VecN<T, R> {
+        VecN(std::array::from_fn(|i| self.0[i].0[j]))
+    }
+    
+    pub fn apply_to(self, v: VecN<T, C>) -> VecN<T, R>
+        where VecN<T, C>: VecOps<T> {
+        VecN(self.0.map(|r| dot(r, v)))
+    }
+}
+
+//only square matrices have an identity, like Mat2/Mat3/Mat4 this is the default
+impl<T: Copy + Zero + One, const N: usize> Default for Mat<T, N, N> {
+    fn default() -> Self {
+        Self::ident()
+    }
+}
+
+impl<T: Copy + Zero + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Abs, const R: usize, const C: usize, const K: usize> Mul<Mat<T, C, K>> for Mat<T, R, C> {
+    type Output = Mat<T, R, K>;
+    fn mul(self, other: Mat<T, C, K>) -> Mat<T, R, K> {
+        let t = other.transpose();
+        Mat(self.0.map(|r| VecN(t.0.map(|c| dot(r, c)))))
+    }
+}
+
+impl<T: Copy + Zero + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Abs, const R: usize, const C: usize> Mul<VecN<T, C>> for Mat<T, R, C> {
+    type Output = VecN<T, R>;
+    fn mul(self, v: VecN<T, C>) -> VecN<T, R> {
+        self.apply_to(v)
+    }
+}
+//no mul assign to avoid confusion as matrix multiplication is not commutative
+impl<T: Copy + Add<Output=T>, const R: usize, const C: usize> Add for Mat<T, R, C> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Mat(std::array::from_fn(|i| self.0[i] + other.0[i]))
+    }
+}
+
+impl<T: Copy + Add<Output=T>, const R: usize, const C: usize> AddAssign for Mat<T, R, C> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Copy + Sub<Output=T>, const R: usize, const C: usize> Sub for Mat<T, R, C> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Mat(std::array::from_fn(|i| self.0[i] - other.0[i]))
+    }
+}
+
+impl<T: Copy + Sub<Output=T>, const R: usize, const C: usize> SubAssign for Mat<T, R, C> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Neg, const R: usize, const C: usize> Neg for Mat<T, R, C> {
+    type Output = Mat<<T as Neg>::Output, R, C>;
+    fn neg(self) -> Self::Output { Mat(self.0.map(|r| -r)) }
+}
+
+impl<T, const R: usize, const C: usize> Index<usize> for Mat<T, R, C> {
+    type Output = VecN<T, C>;
+    fn index(&self, index: usize) -> &VecN<T, C> {
+        &self.0[index]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<usize> for Mat<T, R, C> {
+    fn index_mut(&mut self, index: usize) -> &mut VecN<T, C> {
+        &mut self.0[index]
+    }
+}
+
+impl<T: fmt::Display, const R: usize, const C: usize> fmt::Display for Mat<T, R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, r) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            fmt::Display::fmt(r, f)?;
+        }
+        Ok(())
+    }
+}
+
+//lets Vec2/Vec3/Vec4 be used as columns in products with non-square matrices
+impl<T: Copy + Zero + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Abs, const R: usize> Mul<Vec2<T>> for Mat<T, R, 2> {
+    type Output = VecN<T, R>;
+    fn mul(self, v: Vec2<T>) -> VecN<T, R> {
+        self.apply_to(v.into())
+    }
+}
+impl<T: Copy + Zero + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Abs, const R: usize> Mul<Vec3<T>> for Mat<T, R, 3> {
+    type Output = VecN<T, R>;
+    fn mul(self, v: Vec3<T>) -> VecN<T, R> {
+        self.apply_to(v.into())
+    }
+}
+impl<T: Copy + Zero + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Abs, const R: usize> Mul<Vec4<T>> for Mat<T, R, 4> {
+    type Output = VecN<T, R>;
+    fn mul(self, v: Vec4<T>) -> VecN<T, R> {
+        self.apply_to(v.into())
+    }
+}
@@ -1,7 +1,10 @@
 use crate::prelude::*;
 
 use crate::mat3::*;
+use crate::mat4::*;
 use crate::vec3::*;
+use crate::vec4::*;
+use crate::euler::*;
 
 #[repr(C)]
 #[cfg_attr(feature="serde", derive(Serialize,Deserialize))]
@@ -15,32 +18,49 @@ pub struct Quaternion<T> {
 pub fn quaternion<T>(r: T, i: T, j: T, k: T) -> Quaternion<T> {
     Quaternion { r, i, j, k }
 }
-//TODO: integer quaternions?
-impl<T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Trig + Sqrt<T> + Neg<Output=T> + Abs> Quaternion<T> {
-    pub fn ident() -> Self {
+
+//everything that works on integer quaternions too
+impl<T: Copy + Mul<Output=T> + Add<Output=T> + Neg<Output=T>> Quaternion<T> {
+    pub fn ident() -> Self
+        where T: Zero + One {
         quaternion(T::one(), T::zero(), T::zero(), T::zero())
     }
     
-    pub fn magnitude(self) -> T {
-        (self.i * self.i + self.j * self.j + self.k * self.k + self.r * self.r).sqrt()
+    pub fn conjugate(self) -> Self {
+        quaternion(self.r, -self.i, -self.j, -self.k)
     }
     
-    pub fn normalize(self) -> Self {
-        let mag = self.magnitude();
-        Self { r: self.r / mag, i: self.i / mag, j: self.j / mag, k: self.k / mag }
+    pub fn norm_squared(self) -> T {
+        self.dot(self)
     }
     
-    pub fn inv(self) -> Self {
-        Self { r: -self.r, i: self.i, j: self.j, k: self.k }
+    pub fn dot(self, other: Self) -> T {
+        self.r * other.r + self.i * other.i + self.j * other.j + self.k * other.k
     }
     
-    pub fn from_euler_angles(input: Vec3<T>) -> Self {
-        let (factor, r) = (input.magnitude() / T::two()).sin_cos();
-        let (i, j, k) = (factor * input.x, factor * input.y, factor * input.z);
-        quaternion(r, i, j, k)
+    //the inverse of a normalized quaternion is just its conjugate
+    pub fn inv_unit(self) -> Self {
+        self.conjugate()
     }
     
-    pub fn rot_mat(self) -> Mat3<T> {
+    pub fn is_nan(self) -> bool
+        where T: IsNan {
+        self.r.is_nan() || self.i.is_nan() || self.j.is_nan() || self.k.is_nan()
+    }
+    
+    //self / rhs (= self * rhs.inv()) if every component of it is exact, floats always succeed
+    pub fn exact_div(self, rhs: Self) -> Option<Self>
+        where T: Sub<Output=T> + ExactDiv {
+        let n = rhs.norm_squared();
+        let q = self * rhs.conjugate();
+        Some(quaternion(q.r.exact_div(n)?, q.i.exact_div(n)?, q.j.exact_div(n)?, q.k.exact_div(n)?))
+    }
+}
+
+//these assume a normalized quaternion
+impl<T: Copy + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T>> Quaternion<T> {
+    pub fn rot_mat(self) -> Mat3<T>
+        where T: One {
         let (r, i, j, k) = (self.r, self.i, self.j, self.k);
         mat3(
             vec3(T::one() - T::two() * (j * j + k * k), T::two() * (i * j - r * k), T::two() * (i * k + r * j)),
@@ -48,14 +68,277 @@ impl<T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Div<Output=T>
             vec3(T::two() * (i * k - r * j), T::two() * (j * k + r * i), T::one() - T::two() * (i * i + j * j)),
         )
     }
+    
+    pub fn rotate(self, v: Vec3<T>) -> Vec3<T> {
+        //v + 2r(u x v) + 2u x (u x v) without building the matrix
+        let u = vec3(self.i, self.j, self.k);
+        let t = cross(u, v) * T::two();
+        v + t * self.r + cross(u, t)
+    }
+    
+    //panics if input and output have different lengths
+    pub fn rotate_slice(self, input: &[Vec3<T>], output: &mut [Vec3<T>]) {
+        assert_eq!(input.len(), output.len(), "rotate_slice needs an output for every input");
+        for (o, &v) in output.iter_mut().zip(input) {
+            *o = self.rotate(v);
+        }
+    }
 }
 
-impl<T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Trig + Sqrt<T> + Neg<Output=T> + Abs> Default for Quaternion<T> {
+//see hurwitz.rs for division and factoring of integer quaternions
+impl<T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Trig + Sqrt<T> + Neg<Output=T> + Abs> Quaternion<T> {
+    pub fn magnitude(self) -> T {
+        self.norm_squared().sqrt()
+    }
+    
+    pub fn normalize(self) -> Self {
+        self / self.magnitude()
+    }
+    
+    pub fn inv(self) -> Self {
+        self.conjugate() / self.norm_squared()
+    }
+    
+    //rotation by input.magnitude() radians about the direction of input
+    pub fn from_rotation_vector(input: Vec3<T>) -> Self
+        where T: PartialEq {
+        let angle = input.magnitude();
+        if angle == T::zero() {
+            return Self::ident();
+        }
+        let (sin, r) = (angle / T::two()).sin_cos();
+        let factor = sin / angle;
+        quaternion(r, factor * input.x, factor * input.y, factor * input.z)
+    }
+    
+    //self must be normalized, the inverse of from_rotation_vector with a magnitude of at most pi
+    pub fn to_rotation_vector(self) -> Vec3<T>
+        where T: PartialOrd {
+        let q = if self.r < T::zero() { -self } else { self };
+        q.ln_unit() * T::two()
+    }
+    
+    pub fn from_axis_angle(axis: Vec3<T>, angle: T) -> Self {
+        let (sin, r) = (angle / T::two()).sin_cos();
+        let v = axis.normalize() * sin;
+        quaternion(r, v.x, v.y, v.z)
+    }
+    
+    //self must be normalized, returns a unit axis and an angle in [0, pi]
+    //with no rotation the axis is arbitrary and x is returned
+    pub fn to_axis_angle(self) -> (Vec3<T>, T)
+        where T: PartialOrd {
+        let v = self.to_rotation_vector();
+        let angle = v.magnitude();
+        if angle == T::zero() { (Vec3::ident(), angle) } else { (v / angle, angle) }
+    }
+    
+    //the angle of the smallest rotation that takes self to other, both must be normalized
+    pub fn angle_between(self, other: Self) -> T
+        where T: PartialOrd {
+        (self.conjugate() * other).to_rotation_vector().magnitude()
+    }
+    
+    //the shortest rotation that takes the direction of from to the direction of to
+    pub fn from_rotation_arc(from: Vec3<T>, to: Vec3<T>) -> Self
+        where T: PartialOrd + Epsilon {
+        let (a, b) = (from.normalize(), to.normalize());
+        //with s = a + b, 1 + dot(a, b) = |s|^2 / 2 and cross(a, b) = cross(a, s)
+        //unlike the plain versions these don't lose their digits to cancellation when a and b are nearly opposite
+        let s = a + b;
+        let w = dot(s, s) / T::two();
+        //opposite vectors have no unique arc, so make a half turn about an axis perpendicular to a
+        //(from whichever world axis is least parallel to it) and then take the remaining small arc from -a to b
+        if w <= T::epsilon() * T::epsilon() {
+            let other = if a.x.abs() < a.y.abs() && a.x.abs() < a.z.abs() {
+                vec3(T::one(), T::zero(), T::zero())
+            } else if a.y.abs() < a.z.abs() {
+                vec3(T::zero(), T::one(), T::zero())
+            } else {
+                vec3(T::zero(), T::zero(), T::one())
+            };
+            let axis = cross(a, other).normalize();
+            return Self::from_rotation_arc(-a, b) * quaternion(T::zero(), axis.x, axis.y, axis.z);
+        }
+        let c = cross(a, s);
+        quaternion(w, c.x, c.y, c.z).normalize()
+    }
+    
+    //the rotation that takes +z to forward and +y as close to up as possible
+    //if forward and up are parallel this falls back to from_rotation_arc
+    pub fn look_rotation(forward: Vec3<T>, up: Vec3<T>) -> Self
+        where T: PartialOrd + Epsilon {
+        let z = forward.normalize();
+        let x = cross(up, z);
+        if x.magnitude() <= T::epsilon().sqrt() * up.magnitude() {
+            return Self::from_rotation_arc(vec3(T::zero(), T::zero(), T::one()), z);
+        }
+        let x = x.normalize();
+        let y = cross(z, x);
+        //x, y and z are the columns of the rotation matrix
+        Self::from_rot_mat(mat3(
+            vec3(x.x, y.x, z.x),
+            vec3(x.y, y.y, z.y),
+            vec3(x.z, y.z, z.z),
+        ))
+    }
+    
+    //splits self into self = swing * twist, where twist is a rotation about axis and swing is about an axis perpendicular to it
+    //self must be normalized, when the swing is a half turn the twist is undefined and ident is returned for it
+    pub fn swing_twist(self, axis: Vec3<T>) -> (Self, Self)
+        where T: PartialEq {
+        let axis = axis.normalize();
+        let p = axis * dot(vec3(self.i, self.j, self.k), axis);
+        let twist = quaternion(self.r, p.x, p.y, p.z);
+        if twist.norm_squared() == T::zero() {
+            return (self, Self::ident());
+        }
+        let twist = twist.normalize();
+        (self * twist.conjugate(), twist)
+    }
+    
+    #[deprecated(note="this takes a rotation vector, not euler angles, use from_rotation_vector or from_euler")]
+    pub fn from_euler_angles(input: Vec3<T>) -> Self
+        where T: PartialEq {
+        Self::from_rotation_vector(input)
+    }
+    
+    pub fn from_euler(order: EulerOrder, angles: Vec3<T>) -> Self {
+        let (axes, reversed) = order.to_intrinsic();
+        let [i, j, k] = axes.axes();
+        let (a, b, c) = if reversed { (angles.z, angles.y, angles.x) } else { (angles.x, angles.y, angles.z) };
+        let rotate_axis = |axis: usize, angle: T| {
+            let (sin, cos) = (angle / T::two()).sin_cos();
+            let mut q = quaternion(cos, T::zero(), T::zero(), T::zero());
+            q[axis + 1] = sin;
+            q
+        };
+        rotate_axis(i, a) * rotate_axis(j, b) * rotate_axis(k, c)
+    }
+    
+    //self must be normalized, see Mat3::to_euler for the angle ranges and gimbal lock
+    pub fn to_euler(self, order: EulerOrder) -> Vec3<T>
+        where T: PartialOrd + Epsilon {
+        self.rot_mat().to_euler(order)
+    }
+    
+    //self must be a rotation matrix, uses shepperd's method: the component with the largest magnitude is found from the diagonal
+    //and the others are divided by it, so there is no cancellation near 180 degree turns
+    pub fn from_rot_mat(m: Mat3<T>) -> Self
+        where T: PartialOrd {
+        let four = T::two() * T::two();
+        let trace = m.x.x + m.y.y + m.z.z;
+        if trace >= m.x.x && trace >= m.y.y && trace >= m.z.z {
+            let s = T::two() * (T::one() + trace).sqrt();
+            quaternion(s / four, (m.z.y - m.y.z) / s, (m.x.z - m.z.x) / s, (m.y.x - m.x.y) / s)
+        } else if m.x.x >= m.y.y && m.x.x >= m.z.z {
+            let s = T::two() * (T::one() + m.x.x - m.y.y - m.z.z).sqrt();
+            quaternion((m.z.y - m.y.z) / s, s / four, (m.x.y + m.y.x) / s, (m.x.z + m.z.x) / s)
+        } else if m.y.y >= m.z.z {
+            let s = T::two() * (T::one() + m.y.y - m.x.x - m.z.z).sqrt();
+            quaternion((m.x.z - m.z.x) / s, (m.x.y + m.y.x) / s, s / four, (m.y.z + m.z.y) / s)
+        } else {
+            let s = T::two() * (T::one() + m.z.z - m.x.x - m.y.y).sqrt();
+            quaternion((m.y.x - m.x.y) / s, (m.x.z + m.z.x) / s, (m.y.z + m.z.y) / s, s / four)
+        }
+    }
+    
+    //self and other must be normalized, cheaper than slerp but the speed isn't constant
+    pub fn nlerp(self, other: Self, t: T) -> Self
+        where T: PartialOrd {
+        //q and -q are the same rotation, flipping to the same hemisphere takes the short way around
+        let b = if self.dot(other) < T::zero() { -t } else { t };
+        (self * (T::one() - t) + other * b).normalize()
+    }
+    
+    //constant speed interpolation along the shortest arc, self and other must be normalized
+    pub fn slerp(self, other: Self, t: T) -> Self
+        where T: PartialOrd + Epsilon {
+        let d = self.dot(other);
+        let (d, sign) = if d < T::zero() { (-d, -T::one()) } else { (d, T::one()) };
+        //sin(theta) is too small to divide by, but nlerp is just as good over such a small angle
+        if d > T::one() - T::epsilon().sqrt() {
+            return self.nlerp(other, t);
+        }
+        let theta = d.acos();
+        let sin = theta.sin();
+        self * (((T::one() - t) * theta).sin() / sin) + other * (sign * (t * theta).sin() / sin)
+    }
+    
+    //smooth spline through keyframes q[n], between q[n] and q[n + 1] use q[n].squad(s[n], s[n + 1], q[n + 1], t)
+    //with s[n] = Quaternion::squad_control(q[n - 1], q[n], q[n + 1]) (repeat the first/last keyframe at the ends)
+    pub fn squad(self, s1: Self, s2: Self, other: Self, t: T) -> Self
+        where T: PartialOrd + Epsilon {
+        self.slerp(other, t).slerp(s1.slerp(s2, t), T::two() * t * (T::one() - t))
+    }
+    
+    pub fn squad_control(prev: Self, cur: Self, next: Self) -> Self
+        where T: PartialOrd {
+        let inv = cur.conjugate();
+        let log = |q: Self| {
+            let q = inv * q;
+            (if q.r < T::zero() { quaternion(-q.r, -q.i, -q.j, -q.k) } else { q }).ln_unit()
+        };
+        let v = (log(next) + log(prev)) / -(T::two() * T::two());
+        cur * Self::from_rotation_vector(v * T::two())
+    }
+    
+    pub fn exp(self) -> Self
+        where T: Float + PartialEq {
+        let v = vec3(self.i, self.j, self.k);
+        let angle = v.magnitude();
+        let scale = self.r.exp();
+        if angle == T::zero() {
+            return quaternion(scale, T::zero(), T::zero(), T::zero());
+        }
+        let (sin, cos) = angle.sin_cos();
+        let v = v * (scale * sin / angle);
+        quaternion(scale * cos, v.x, v.y, v.z)
+    }
+    
+    //the principal log, whose vector part has a magnitude of at most pi
+    pub fn ln(self) -> Self
+        where T: Float + PartialEq {
+        let v = self.normalize().ln_unit();
+        quaternion(self.magnitude().ln(), v.x, v.y, v.z)
+    }
+    
+    pub fn powf(self, t: T) -> Self
+        where T: Float + PartialEq {
+        (self.ln() * t).exp()
+    }
+    
+    //log of a normalized quaternion, the real part is always 0 so only the vector part is returned
+    fn ln_unit(self) -> Vec3<T>
+        where T: PartialEq {
+        let v = vec3(self.i, self.j, self.k);
+        let sin = v.magnitude();
+        if sin == T::zero() {
+            return Vec3::zero();
+        }
+        v * (sin.atan2(self.r) / sin)
+    }
+}
+
+impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Neg<Output=T>> Default for Quaternion<T> {
     fn default() -> Self {
         Quaternion::ident()
     }
 }
 
+impl<T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Trig + Sqrt<T> + Neg<Output=T> + Abs + PartialOrd> From<Mat3<T>> for Quaternion<T> {
+    fn from(m: Mat3<T>) -> Self {
+        Quaternion::from_rot_mat(m)
+    }
+}
+
+//only the upper left 3x3 is used, so this also works on transforms with a translation
+impl<T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Trig + Sqrt<T> + Neg<Output=T> + Abs + PartialOrd> From<Mat4<T>> for Quaternion<T> {
+    fn from(m: Mat4<T>) -> Self {
+        Quaternion::from_rot_mat(m.downsize())
+    }
+}
+
 impl<T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T>> Mul<Quaternion<T>> for Quaternion<T> {
     type Output = Self;
     fn mul(self, other: Self) -> Self {
@@ -68,9 +351,141 @@ impl<T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T>> Mul<Quaternion<T>>
     }
 }
 
-impl<T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Trig + Sqrt<T> + Neg<Output=T> + Abs> Product<Quaternion<T>> for Quaternion<T> {
+impl<T: Copy + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T>> Mul<Vec3<T>> for Quaternion<T> {
+    type Output = Vec3<T>;
+    fn mul(self, v: Vec3<T>) -> Vec3<T> {
+        self.rotate(v)
+    }
+}
+
+//a / b = a * b.inv()
+//multiplies before dividing so integer quotients only truncate at the end like integer division, exact_div says whether they did
+impl<T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T>> Div<Quaternion<T>> for Quaternion<T> {
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        (self * other.conjugate()) / other.norm_squared()
+    }
+}
+
+//a *= b is a = a * b, so b is applied first when rotating
+impl<T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T>> MulAssign<Quaternion<T>> for Quaternion<T> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T>> DivAssign<Quaternion<T>> for Quaternion<T> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<T: Add<Output=T>> Add for Quaternion<T> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        quaternion(self.r + other.r, self.i + other.i, self.j + other.j, self.k + other.k)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Quaternion<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.r += other.r;
+        self.i += other.i;
+        self.j += other.j;
+        self.k += other.k;
+    }
+}
+
+impl<T: Sub<Output=T>> Sub for Quaternion<T> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        quaternion(self.r - other.r, self.i - other.i, self.j - other.j, self.k - other.k)
+    }
+}
+
+impl<T: SubAssign> SubAssign for Quaternion<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.r -= other.r;
+        self.i -= other.i;
+        self.j -= other.j;
+        self.k -= other.k;
+    }
+}
+
+impl<T: Copy + Mul<Output=T>> Mul<T> for Quaternion<T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        quaternion(self.r * rhs, self.i * rhs, self.j * rhs, self.k * rhs)
+    }
+}
+
+impl<T: Copy + MulAssign> MulAssign<T> for Quaternion<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.r *= rhs;
+        self.i *= rhs;
+        self.j *= rhs;
+        self.k *= rhs;
+    }
+}
+
+impl<T: Copy + Div<Output=T>> Div<T> for Quaternion<T> {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self {
+        quaternion(self.r / rhs, self.i / rhs, self.j / rhs, self.k / rhs)
+    }
+}
+
+impl<T: Copy + DivAssign> DivAssign<T> for Quaternion<T> {
+    fn div_assign(&mut self, rhs: T) {
+        self.r /= rhs;
+        self.i /= rhs;
+        self.j /= rhs;
+        self.k /= rhs;
+    }
+}
+
+impl<T: Neg> Neg for Quaternion<T> {
+    type Output = Quaternion<<T as Neg>::Output>;
+    fn neg(self) -> Self::Output {
+        quaternion(-self.r, -self.i, -self.j, -self.k)
+    }
+}
+
+impl<T: Zero> Zero for Quaternion<T> {
+    fn zero() -> Self {
+        quaternion(T::zero(), T::zero(), T::zero(), T::zero())
+    }
+}
+
+//the multiplicative identity, not all ones like the vectors
+impl<T: Zero + One> One for Quaternion<T> {
+    fn one() -> Self {
+        quaternion(T::one(), T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Sub<Output=T>> Product<Quaternion<T>> for Quaternion<T> {
     fn product<I: Iterator<Item=Self>>(iter: I) -> Self {
-        iter.fold(Self::ident(), |a, b| a * b)
+        iter.fold(Self::one(), |a, b| a * b)
+    }
+}
+
+impl<T: Zero + Add<Output=T>> Sum<Quaternion<T>> for Quaternion<T> {
+    fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
+
+//same layout as raylib, x y z are the imaginary parts and w is the real part
+impl<T> From<Vec4<T>> for Quaternion<T> {
+    fn from(v: Vec4<T>) -> Self {
+        quaternion(v.w, v.x, v.y, v.z)
+    }
+}
+
+impl<T> From<Quaternion<T>> for Vec4<T> {
+    fn from(q: Quaternion<T>) -> Self {
+        vec4(q.i, q.j, q.k, q.r)
     }
 }
 
@@ -0,0 +1,197 @@
+use crate::prelude::*;
+use crate::vec2::*;
+use crate::vec3::*;
+use crate::vec4::*;
+
+//companion to impl_vec!, kept separate since the lists below are long
+//read swizzles are every combination of 2 to 4 components, setters are every ordering of distinct components
+macro impl_swizzle {
+    ($type: ident, get { $($name: ident: $out: ident($($f: ident),*)),* $(,)? }) => {
+        impl<T: Copy> $type<T> {
+            $(
+                pub fn $name(self) -> $out<T> {
+                    [$(self.$f),*].into()
+                }
+            )*
+        }
+    },
+    ($type: ident, set1 { $($name: ident($f: ident)),* $(,)? }) => {
+        impl<T> $type<T> {
+            $(
+                pub fn $name(self, $f: T) -> Self {
+                    $type { $f, ..self }
+                }
+            )*
+        }
+    },
+    ($type: ident, set { $($name: ident: $in: ident($($f: ident),*)),* $(,)? }) => {
+        #[allow(clippy::needless_update)] //setting every field is just a reorder, e.g. Vec2::with_yx
+        impl<T> $type<T> {
+            $(
+                pub fn $name(self, v: $in<T>) -> Self {
+                    let [$($f),*]: [T; _] = v.into();
+                    $type { $($f,)* ..self }
+                }
+            )*
+        }
+    },
+}
+
+impl_swizzle!(Vec2, get {
+    xx: Vec2(x, x), xy: Vec2(x, y), yx: Vec2(y, x), yy: Vec2(y, y),
+    xxx: Vec3(x, x, x), xxy: Vec3(x, x, y), xyx: Vec3(x, y, x), xyy: Vec3(x, y, y), yxx: Vec3(y, x, x),
+    yxy: Vec3(y, x, y), yyx: Vec3(y, y, x), yyy: Vec3(y, y, y),
+    xxxx: Vec4(x, x, x, x), xxxy: Vec4(x, x, x, y), xxyx: Vec4(x, x, y, x), xxyy: Vec4(x, x, y, y),
+    xyxx: Vec4(x, y, x, x), xyxy: Vec4(x, y, x, y), xyyx: Vec4(x, y, y, x), xyyy: Vec4(x, y, y, y),
+    yxxx: Vec4(y, x, x, x), yxxy: Vec4(y, x, x, y), yxyx: Vec4(y, x, y, x), yxyy: Vec4(y, x, y, y),
+    yyxx: Vec4(y, y, x, x), yyxy: Vec4(y, y, x, y), yyyx: Vec4(y, y, y, x), yyyy: Vec4(y, y, y, y),
+});
+impl_swizzle!(Vec2, set1 {
+    with_x(x), with_y(y),
+});
+impl_swizzle!(Vec2, set {
+    with_xy: Vec2(x, y), with_yx: Vec2(y, x),
+});
+
+impl_swizzle!(Vec3, get {
+    xx: Vec2(x, x), xy: Vec2(x, y), xz: Vec2(x, z), yx: Vec2(y, x), yy: Vec2(y, y), yz: Vec2(y, z),
+    zx: Vec2(z, x), zy: Vec2(z, y), zz: Vec2(z, z),
+    xxx: Vec3(x, x, x), xxy: Vec3(x, x, y), xxz: Vec3(x, x, z), xyx: Vec3(x, y, x), xyy: Vec3(x, y, y),
+    xyz: Vec3(x, y, z), xzx: Vec3(x, z, x), xzy: Vec3(x, z, y), xzz: Vec3(x, z, z), yxx: Vec3(y, x, x),
+    yxy: Vec3(y, x, y), yxz: Vec3(y, x, z), yyx: Vec3(y, y, x), yyy: Vec3(y, y, y), yyz: Vec3(y, y, z),
+    yzx: Vec3(y, z, x), yzy: Vec3(y, z, y), yzz: Vec3(y, z, z), zxx: Vec3(z, x, x), zxy: Vec3(z, x, y),
+    zxz: Vec3(z, x, z), zyx: Vec3(z, y, x), zyy: Vec3(z, y, y), zyz: Vec3(z, y, z), zzx: Vec3(z, z, x),
+    zzy: Vec3(z, z, y), zzz: Vec3(z, z, z),
+    xxxx: Vec4(x, x, x, x), xxxy: Vec4(x, x, x, y), xxxz: Vec4(x, x, x, z), xxyx: Vec4(x, x, y, x),
+    xxyy: Vec4(x, x, y, y), xxyz: Vec4(x, x, y, z), xxzx: Vec4(x, x, z, x), xxzy: Vec4(x, x, z, y),
+    xxzz: Vec4(x, x, z, z), xyxx: Vec4(x, y, x, x), xyxy: Vec4(x, y, x, y), xyxz: Vec4(x, y, x, z),
+    xyyx: Vec4(x, y, y, x), xyyy: Vec4(x, y, y, y), xyyz: Vec4(x, y, y, z), xyzx: Vec4(x, y, z, x),
+    xyzy: Vec4(x, y, z, y), xyzz: Vec4(x, y, z, z), xzxx: Vec4(x, z, x, x), xzxy: Vec4(x, z, x, y),
+    xzxz: Vec4(x, z, x, z), xzyx: Vec4(x, z, y, x), xzyy: Vec4(x, z, y, y), xzyz: Vec4(x, z, y, z),
+    xzzx: Vec4(x, z, z, x), xzzy: Vec4(x, z, z, y), xzzz: Vec4(x, z, z, z), yxxx: Vec4(y, x, x, x),
+    yxxy: Vec4(y, x, x, y), yxxz: Vec4(y, x, x, z), yxyx: Vec4(y, x, y, x), yxyy: Vec4(y, x, y, y),
+    yxyz: Vec4(y, x, y, z), yxzx: Vec4(y, x, z, x), yxzy: Vec4(y, x, z, y), yxzz: Vec4(y, x, z, z),
+    yyxx: Vec4(y, y, x, x), yyxy: Vec4(y, y, x, y), yyxz: Vec4(y, y, x, z), yyyx: Vec4(y, y, y, x),
+    yyyy: Vec4(y, y, y, y), yyyz: Vec4(y, y, y, z), yyzx: Vec4(y, y, z, x), yyzy: Vec4(y, y, z, y),
+    yyzz: Vec4(y, y, z, z), yzxx: Vec4(y, z, x, x), yzxy: Vec4(y, z, x, y), yzxz: Vec4(y, z, x, z),
+    yzyx: Vec4(y, z, y, x), yzyy: Vec4(y, z, y, y), yzyz: Vec4(y, z, y, z), yzzx: Vec4(y, z, z, x),
+    yzzy: Vec4(y, z, z, y), yzzz: Vec4(y, z, z, z), zxxx: Vec4(z, x, x, x), zxxy: Vec4(z, x, x, y),
+    zxxz: Vec4(z, x, x, z), zxyx: Vec4(z, x, y, x), zxyy: Vec4(z, x, y, y), zxyz: Vec4(z, x, y, z),
+    zxzx: Vec4(z, x, z, x), zxzy: Vec4(z, x, z, y), zxzz: Vec4(z, x, z, z), zyxx: Vec4(z, y, x, x),
+    zyxy: Vec4(z, y, x, y), zyxz: Vec4(z, y, x, z), zyyx: Vec4(z, y, y, x), zyyy: Vec4(z, y, y, y),
+    zyyz: Vec4(z, y, y, z), zyzx: Vec4(z, y, z, x), zyzy: Vec4(z, y, z, y), zyzz: Vec4(z, y, z, z),
+    zzxx: Vec4(z, z, x, x), zzxy: Vec4(z, z, x, y), zzxz: Vec4(z, z, x, z), zzyx: Vec4(z, z, y, x),
+    zzyy: Vec4(z, z, y, y), zzyz: Vec4(z, z, y, z), zzzx: Vec4(z, z, z, x), zzzy: Vec4(z, z, z, y),
+    zzzz: Vec4(z, z, z, z),
+});
+impl_swizzle!(Vec3, set1 {
+    with_x(x), with_y(y), with_z(z),
+});
+impl_swizzle!(Vec3, set {
+    with_xy: Vec2(x, y), with_xz: Vec2(x, z), with_yx: Vec2(y, x), with_yz: Vec2(y, z),
+    with_zx: Vec2(z, x), with_zy: Vec2(z, y), with_xyz: Vec3(x, y, z), with_xzy: Vec3(x, z, y),
+    with_yxz: Vec3(y, x, z), with_yzx: Vec3(y, z, x), with_zxy: Vec3(z, x, y), with_zyx: Vec3(z, y, x),
+});
+
+impl_swizzle!(Vec4, get {
+    xx: Vec2(x, x), xy: Vec2(x, y), xz: Vec2(x, z), xw: Vec2(x, w), yx: Vec2(y, x), yy: Vec2(y, y),
+    yz: Vec2(y, z), yw: Vec2(y, w), zx: Vec2(z, x), zy: Vec2(z, y), zz: Vec2(z, z), zw: Vec2(z, w),
+    wx: Vec2(w, x), wy: Vec2(w, y), wz: Vec2(w, z), ww: Vec2(w, w),
+    xxx: Vec3(x, x, x), xxy: Vec3(x, x, y), xxz: Vec3(x, x, z), xxw: Vec3(x, x, w), xyx: Vec3(x, y, x),
+    xyy: Vec3(x, y, y), xyz: Vec3(x, y, z), xyw: Vec3(x, y, w), xzx: Vec3(x, z, x), xzy: Vec3(x, z, y),
+    xzz: Vec3(x, z, z), xzw: Vec3(x, z, w), xwx: Vec3(x, w, x), xwy: Vec3(x, w, y), xwz: Vec3(x, w, z),
+    xww: Vec3(x, w, w), yxx: Vec3(y, x, x), yxy: Vec3(y, x, y), yxz: Vec3(y, x, z), yxw: Vec3(y, x, w),
+    yyx: Vec3(y, y, x), yyy: Vec3(y, y, y), yyz: Vec3(y, y, z), yyw: Vec3(y, y, w), yzx: Vec3(y, z, x),
+    yzy: Vec3(y, z, y), yzz: Vec3(y, z, z), yzw: Vec3(y, z, w), ywx: Vec3(y, w, x), ywy: Vec3(y, w, y),
+    ywz: Vec3(y, w, z), yww: Vec3(y, w, w), zxx: Vec3(z, x, x), zxy: Vec3(z, x, y), zxz: Vec3(z, x, z),
+    zxw: Vec3(z, x, w), zyx: Vec3(z, y, x), zyy: Vec3(z, y, y), zyz: Vec3(z, y, z), zyw: Vec3(z, y, w),
+    zzx: Vec3(z, z, x), zzy: Vec3(z, z, y), zzz: Vec3(z, z, z), zzw: Vec3(z, z, w), zwx: Vec3(z, w, x),
+    zwy: Vec3(z, w, y), zwz: Vec3(z, w, z), zww: Vec3(z, w, w), wxx: Vec3(w, x, x), wxy: Vec3(w, x, y),
+    wxz: Vec3(w, x, z), wxw: Vec3(w, x, w), wyx: Vec3(w, y, x), wyy: Vec3(w, y, y), wyz: Vec3(w, y, z),
+    wyw: Vec3(w, y, w), wzx: Vec3(w, z, x), wzy: Vec3(w, z, y), wzz: Vec3(w, z, z), wzw: Vec3(w, z, w),
+    wwx: Vec3(w, w, x), wwy: Vec3(w, w, y), wwz: Vec3(w, w, z), www: Vec3(w, w, w),
+    xxxx: Vec4(x, x, x, x), xxxy: Vec4(x, x, x, y), xxxz: Vec4(x, x, x, z), xxxw: Vec4(x, x, x, w),
+    xxyx: Vec4(x, x, y, x), xxyy: Vec4(x, x, y, y), xxyz: Vec4(x, x, y, z), xxyw: Vec4(x, x, y, w),
+    xxzx: Vec4(x, x, z, x), xxzy: Vec4(x, x, z, y), xxzz: Vec4(x, x, z, z), xxzw: Vec4(x, x, z, w),
+    xxwx: Vec4(x, x, w, x), xxwy: Vec4(x, x, w, y), xxwz: Vec4(x, x, w, z), xxww: Vec4(x, x, w, w),
+    xyxx: Vec4(x, y, x, x), xyxy: Vec4(x, y, x, y), xyxz: Vec4(x, y, x, z), xyxw: Vec4(x, y, x, w),
+    xyyx: Vec4(x, y, y, x), xyyy: Vec4(x, y, y, y), xyyz: Vec4(x, y, y, z), xyyw: Vec4(x, y, y, w),
+    xyzx: Vec4(x, y, z, x), xyzy: Vec4(x, y, z, y), xyzz: Vec4(x, y, z, z), xyzw: Vec4(x, y, z, w),
+    xywx: Vec4(x, y, w, x), xywy: Vec4(x, y, w, y), xywz: Vec4(x, y, w, z), xyww: Vec4(x, y, w, w),
+    xzxx: Vec4(x, z, x, x), xzxy: Vec4(x, z, x, y), xzxz: Vec4(x, z, x, z), xzxw: Vec4(x, z, x, w),
+    xzyx: Vec4(x, z, y, x), xzyy: Vec4(x, z, y, y), xzyz: Vec4(x, z, y, z), xzyw: Vec4(x, z, y, w),
+    xzzx: Vec4(x, z, z, x), xzzy: Vec4(x, z, z, y), xzzz: Vec4(x, z, z, z), xzzw: Vec4(x, z, z, w),
+    xzwx: Vec4(x, z, w, x), xzwy: Vec4(x, z, w, y), xzwz: Vec4(x, z, w, z), xzww: Vec4(x, z, w, w),
+    xwxx: Vec4(x, w, x, x), xwxy: Vec4(x, w, x, y), xwxz: Vec4(x, w, x, z), xwxw: Vec4(x, w, x, w),
+    xwyx: Vec4(x, w, y, x), xwyy: Vec4(x, w, y, y), xwyz: Vec4(x, w, y, z), xwyw: Vec4(x, w, y, w),
+    xwzx: Vec4(x, w, z, x), xwzy: Vec4(x, w, z, y), xwzz: Vec4(x, w, z, z), xwzw: Vec4(x, w, z, w),
+    xwwx: Vec4(x, w, w, x), xwwy: Vec4(x, w, w, y), xwwz: Vec4(x, w, w, z), xwww: Vec4(x, w, w, w),
+    yxxx: Vec4(y, x, x, x), yxxy: Vec4(y, x, x, y), yxxz: Vec4(y, x, x, z), yxxw: Vec4(y, x, x, w),
+    yxyx: Vec4(y, x, y, x), yxyy: Vec4(y, x, y, y), yxyz: Vec4(y, x, y, z), yxyw: Vec4(y, x, y, w),
+    yxzx: Vec4(y, x, z, x), yxzy: Vec4(y, x, z, y), yxzz: Vec4(y, x, z, z), yxzw: Vec4(y, x, z, w),
+    yxwx: Vec4(y, x, w, x), yxwy: Vec4(y, x, w, y), yxwz: Vec4(y, x, w, z), yxww: Vec4(y, x, w, w),
+    yyxx: Vec4(y, y, x, x), yyxy: Vec4(y, y, x, y), yyxz: Vec4(y, y, x, z), yyxw: Vec4(y, y, x, w),
+    yyyx: Vec4(y, y, y, x), yyyy: Vec4(y, y, y, y), yyyz: Vec4(y, y, y, z), yyyw: Vec4(y, y, y, w),
+    yyzx: Vec4(y, y, z, x), yyzy: Vec4(y, y, z, y), yyzz: Vec4(y, y, z, z), yyzw: Vec4(y, y, z, w),
+    yywx: Vec4(y, y, w, x), yywy: Vec4(y, y, w, y), yywz: Vec4(y, y, w, z), yyww: Vec4(y, y, w, w),
+    yzxx: Vec4(y, z, x, x), yzxy: Vec4(y, z, x, y), yzxz: Vec4(y, z, x, z), yzxw: Vec4(y, z, x, w),
+    yzyx: Vec4(y, z, y, x), yzyy: Vec4(y, z, y, y), yzyz: Vec4(y, z, y, z), yzyw: Vec4(y, z, y, w),
+    yzzx: Vec4(y, z, z, x), yzzy: Vec4(y, z, z, y), yzzz: Vec4(y, z, z, z), yzzw: Vec4(y, z, z, w),
+    yzwx: Vec4(y, z, w, x), yzwy: Vec4(y, z, w, y), yzwz: Vec4(y, z, w, z), yzww: Vec4(y, z, w, w),
+    ywxx: Vec4(y, w, x, x), ywxy: Vec4(y, w, x, y), ywxz: Vec4(y, w, x, z), ywxw: Vec4(y, w, x, w),
+    ywyx: Vec4(y, w, y, x), ywyy: Vec4(y, w, y, y), ywyz: Vec4(y, w, y, z), ywyw: Vec4(y, w, y, w),
+    ywzx: Vec4(y, w, z, x), ywzy: Vec4(y, w, z, y), ywzz: Vec4(y, w, z, z), ywzw: Vec4(y, w, z, w),
+    ywwx: Vec4(y, w, w, x), ywwy: Vec4(y, w, w, y), ywwz: Vec4(y, w, w, z), ywww: Vec4(y, w, w, w),
+    zxxx: Vec4(z, x, x, x), zxxy: Vec4(z, x, x, y), zxxz: Vec4(z, x, x, z), zxxw: Vec4(z, x, x, w),
+    zxyx: Vec4(z, x, y, x), zxyy: Vec4(z, x, y, y), zxyz: Vec4(z, x, y, z), zxyw: Vec4(z, x, y, w),
+    zxzx: Vec4(z, x, z, x), zxzy: Vec4(z, x, z, y), zxzz: Vec4(z, x, z, z), zxzw: Vec4(z, x, z, w),
+    zxwx: Vec4(z, x, w, x), zxwy: Vec4(z, x, w, y), zxwz: Vec4(z, x, w, z), zxww: Vec4(z, x, w, w),
+    zyxx: Vec4(z, y, x, x), zyxy: Vec4(z, y, x, y), zyxz: Vec4(z, y, x, z), zyxw: Vec4(z, y, x, w),
+    zyyx: Vec4(z, y, y, x), zyyy: Vec4(z, y, y, y), zyyz: Vec4(z, y, y, z), zyyw: Vec4(z, y, y, w),
+    zyzx: Vec4(z, y, z, x), zyzy: Vec4(z, y, z, y), zyzz: Vec4(z, y, z, z), zyzw: Vec4(z, y, z, w),
+    zywx: Vec4(z, y, w, x), zywy: Vec4(z, y, w, y), zywz: Vec4(z, y, w, z), zyww: Vec4(z, y, w, w),
+    zzxx: Vec4(z, z, x, x), zzxy: Vec4(z, z, x, y), zzxz: Vec4(z, z, x, z), zzxw: Vec4(z, z, x, w),
+    zzyx: Vec4(z, z, y, x), zzyy: Vec4(z, z, y, y), zzyz: Vec4(z, z, y, z), zzyw: Vec4(z, z, y, w),
+    zzzx: Vec4(z, z, z, x), zzzy: Vec4(z, z, z, y), zzzz: Vec4(z, z, z, z), zzzw: Vec4(z, z, z, w),
+    zzwx: Vec4(z, z, w, x), zzwy: Vec4(z, z, w, y), zzwz: Vec4(z, z, w, z), zzww: Vec4(z, z, w, w),
+    zwxx: Vec4(z, w, x, x), zwxy: Vec4(z, w, x, y), zwxz: Vec4(z, w, x, z), zwxw: Vec4(z, w, x, w),
+    zwyx: Vec4(z, w, y, x), zwyy: Vec4(z, w, y, y), zwyz: Vec4(z, w, y, z), zwyw: Vec4(z, w, y, w),
+    zwzx: Vec4(z, w, z, x), zwzy: Vec4(z, w, z, y), zwzz: Vec4(z, w, z, z), zwzw: Vec4(z, w, z, w),
+    zwwx: Vec4(z, w, w, x), zwwy: Vec4(z, w, w, y), zwwz: Vec4(z, w, w, z), zwww: Vec4(z, w, w, w),
+    wxxx: Vec4(w, x, x, x), wxxy: Vec4(w, x, x, y), wxxz: Vec4(w, x, x, z), wxxw: Vec4(w, x, x, w),
+    wxyx: Vec4(w, x, y, x), wxyy: Vec4(w, x, y, y), wxyz: Vec4(w, x, y, z), wxyw: Vec4(w, x, y, w),
+    wxzx: Vec4(w, x, z, x), wxzy: Vec4(w, x, z, y), wxzz: Vec4(w, x, z, z), wxzw: Vec4(w, x, z, w),
+    wxwx: Vec4(w, x, w, x), wxwy: Vec4(w, x, w, y), wxwz: Vec4(w, x, w, z), wxww: Vec4(w, x, w, w),
+    wyxx: Vec4(w, y, x, x), wyxy: Vec4(w, y, x, y), wyxz: Vec4(w, y, x, z), wyxw: Vec4(w, y, x, w),
+    wyyx: Vec4(w, y, y, x), wyyy: Vec4(w, y, y, y), wyyz: Vec4(w, y, y, z), wyyw: Vec4(w, y, y, w),
+    wyzx: Vec4(w, y, z, x), wyzy: Vec4(w, y, z, y), wyzz: Vec4(w, y, z, z), wyzw: Vec4(w, y, z, w),
+    wywx: Vec4(w, y, w, x), wywy: Vec4(w, y, w, y), wywz: Vec4(w, y, w, z), wyww: Vec4(w, y, w, w),
+    wzxx: Vec4(w, z, x, x), wzxy: Vec4(w, z, x, y), wzxz: Vec4(w, z, x, z), wzxw: Vec4(w, z, x, w),
+    wzyx: Vec4(w, z, y, x), wzyy: Vec4(w, z, y, y), wzyz: Vec4(w, z, y, z), wzyw: Vec4(w, z, y, w),
+    wzzx: Vec4(w, z, z, x), wzzy: Vec4(w, z, z, y), wzzz: Vec4(w, z, z, z), wzzw: Vec4(w, z, z, w),
+    wzwx: Vec4(w, z, w, x), wzwy: Vec4(w, z, w, y), wzwz: Vec4(w, z, w, z), wzww: Vec4(w, z, w, w),
+    wwxx: Vec4(w, w, x, x), wwxy: Vec4(w, w, x, y), wwxz: Vec4(w, w, x, z), wwxw: Vec4(w, w, x, w),
+    wwyx: Vec4(w, w, y, x), wwyy: Vec4(w, w, y, y), wwyz: Vec4(w, w, y, z), wwyw: Vec4(w, w, y, w),
+    wwzx: Vec4(w, w, z, x), wwzy: Vec4(w, w, z, y), wwzz: Vec4(w, w, z, z), wwzw: Vec4(w, w, z, w),
+    wwwx: Vec4(w, w, w, x), wwwy: Vec4(w, w, w, y), wwwz: Vec4(w, w, w, z), wwww: Vec4(w, w, w, w),
+});
+impl_swizzle!(Vec4, set1 {
+    with_x(x), with_y(y), with_z(z), with_w(w),
+});
+impl_swizzle!(Vec4, set {
+    with_xy: Vec2(x, y), with_xz: Vec2(x, z), with_xw: Vec2(x, w), with_yx: Vec2(y, x),
+    with_yz: Vec2(y, z), with_yw: Vec2(y, w), with_zx: Vec2(z, x), with_zy: Vec2(z, y),
+    with_zw: Vec2(z, w), with_wx: Vec2(w, x), with_wy: Vec2(w, y), with_wz: Vec2(w, z),
+    with_xyz: Vec3(x, y, z), with_xyw: Vec3(x, y, w), with_xzy: Vec3(x, z, y), with_xzw: Vec3(x, z, w),
+    with_xwy: Vec3(x, w, y), with_xwz: Vec3(x, w, z), with_yxz: Vec3(y, x, z), with_yxw: Vec3(y, x, w),
+    with_yzx: Vec3(y, z, x), with_yzw: Vec3(y, z, w), with_ywx: Vec3(y, w, x), with_ywz: Vec3(y, w, z),
+    with_zxy: Vec3(z, x, y), with_zxw: Vec3(z, x, w), with_zyx: Vec3(z, y, x), with_zyw: Vec3(z, y, w),
+    with_zwx: Vec3(z, w, x), with_zwy: Vec3(z, w, y), with_wxy: Vec3(w, x, y), with_wxz: Vec3(w, x, z),
+    with_wyx: Vec3(w, y, x), with_wyz: Vec3(w, y, z), with_wzx: Vec3(w, z, x), with_wzy: Vec3(w, z, y),
+    with_xyzw: Vec4(x, y, z, w), with_xywz: Vec4(x, y, w, z), with_xzyw: Vec4(x, z, y, w), with_xzwy: Vec4(x, z, w, y),
+    with_xwyz: Vec4(x, w, y, z), with_xwzy: Vec4(x, w, z, y), with_yxzw: Vec4(y, x, z, w), with_yxwz: Vec4(y, x, w, z),
+    with_yzxw: Vec4(y, z, x, w), with_yzwx: Vec4(y, z, w, x), with_ywxz: Vec4(y, w, x, z), with_ywzx: Vec4(y, w, z, x),
+    with_zxyw: Vec4(z, x, y, w), with_zxwy: Vec4(z, x, w, y), with_zyxw: Vec4(z, y, x, w), with_zywx: Vec4(z, y, w, x),
+    with_zwxy: Vec4(z, w, x, y), with_zwyx: Vec4(z, w, y, x), with_wxyz: Vec4(w, x, y, z), with_wxzy: Vec4(w, x, z, y),
+    with_wyxz: Vec4(w, y, x, z), with_wyzx: Vec4(w, y, z, x), with_wzxy: Vec4(w, z, x, y), with_wzyx: Vec4(w, z, y, x),
+});
@@ -0,0 +1,304 @@
+use super::*;
+
+//the std numeric methods as traits, so vectors can implement them component-wise (see impl_component_wise!)
+//Bool/U32/Unsigned are the scalar result types for numbers and the matching vector types for vectors
+
+pub trait Float: Copy + Trig + Sqrt<Self> {
+    type Bool;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn fract(self) -> Self;
+    fn signum(self) -> Self;
+    fn recip(self) -> Self;
+    fn exp(self) -> Self;
+    fn exp2(self) -> Self;
+    fn exp_m1(self) -> Self;
+    fn ln(self) -> Self;
+    fn ln_1p(self) -> Self;
+    fn log2(self) -> Self;
+    fn log10(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn to_degrees(self) -> Self;
+    fn to_radians(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn log(self, base: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn is_infinite(self) -> Self::Bool;
+    fn is_finite(self) -> Self::Bool;
+    fn is_normal(self) -> Self::Bool;
+    fn is_sign_positive(self) -> Self::Bool;
+    fn is_sign_negative(self) -> Self::Bool;
+}
+
+pub trait Int: Copy {
+    type U32;
+    type Unsigned;
+    fn count_ones(self) -> Self::U32;
+    fn count_zeros(self) -> Self::U32;
+    fn leading_zeros(self) -> Self::U32;
+    fn trailing_zeros(self) -> Self::U32;
+    fn leading_ones(self) -> Self::U32;
+    fn trailing_ones(self) -> Self::U32;
+    fn swap_bytes(self) -> Self;
+    fn reverse_bits(self) -> Self;
+    fn to_be(self) -> Self;
+    fn to_le(self) -> Self;
+    fn wrapping_neg(self) -> Self;
+    fn pow(self, exp: u32) -> Self;
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+    fn abs_diff(self, rhs: Self) -> Self::Unsigned;
+}
+
+pub trait SignedInt: Int {
+    type Bool;
+    fn signum(self) -> Self;
+    fn wrapping_abs(self) -> Self;
+    fn unsigned_abs(self) -> Self::Unsigned;
+    fn is_positive(self) -> Self::Bool;
+    fn is_negative(self) -> Self::Bool;
+}
+
+pub trait Euclid: Copy {
+    fn div_euclid(self, rhs: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro impl_float($($t: ident),*) {
+    $(
+        impl Float for $t {
+            type Bool = bool;
+            fn floor(self) -> Self { self.floor() }
+            fn ceil(self) -> Self { self.ceil() }
+            fn round(self) -> Self { self.round() }
+            fn trunc(self) -> Self { self.trunc() }
+            fn fract(self) -> Self { self.fract() }
+            fn signum(self) -> Self { self.signum() }
+            fn recip(self) -> Self { self.recip() }
+            fn exp(self) -> Self { self.exp() }
+            fn exp2(self) -> Self { self.exp2() }
+            fn exp_m1(self) -> Self { self.exp_m1() }
+            fn ln(self) -> Self { self.ln() }
+            fn ln_1p(self) -> Self { self.ln_1p() }
+            fn log2(self) -> Self { self.log2() }
+            fn log10(self) -> Self { self.log10() }
+            fn cbrt(self) -> Self { self.cbrt() }
+            fn to_degrees(self) -> Self { self.to_degrees() }
+            fn to_radians(self) -> Self { self.to_radians() }
+            fn powi(self, n: i32) -> Self { self.powi(n) }
+            fn powf(self, n: Self) -> Self { self.powf(n) }
+            fn log(self, base: Self) -> Self { self.log(base) }
+            fn hypot(self, other: Self) -> Self { self.hypot(other) }
+            fn copysign(self, sign: Self) -> Self { self.copysign(sign) }
+            fn mul_add(self, a: Self, b: Self) -> Self { self.mul_add(a, b) }
+            fn is_infinite(self) -> bool { self.is_infinite() }
+            fn is_finite(self) -> bool { self.is_finite() }
+            fn is_normal(self) -> bool { self.is_normal() }
+            fn is_sign_positive(self) -> bool { self.is_sign_positive() }
+            fn is_sign_negative(self) -> bool { self.is_sign_negative() }
+        }
+        
+        impl Euclid for $t {
+            fn div_euclid(self, rhs: Self) -> Self { self.div_euclid(rhs) }
+            fn rem_euclid(self, rhs: Self) -> Self { self.rem_euclid(rhs) }
+        }
+    )*
+}
+
+macro impl_int($($t: ident, $u: ident);*) {
+    $(
+        impl Int for $t {
+            type U32 = u32;
+            type Unsigned = $u;
+            fn count_ones(self) -> u32 { self.count_ones() }
+            fn count_zeros(self) -> u32 { self.count_zeros() }
+            fn leading_zeros(self) -> u32 { self.leading_zeros() }
+            fn trailing_zeros(self) -> u32 { self.trailing_zeros() }
+            fn leading_ones(self) -> u32 { self.leading_ones() }
+            fn trailing_ones(self) -> u32 { self.trailing_ones() }
+            fn swap_bytes(self) -> Self { self.swap_bytes() }
+            fn reverse_bits(self) -> Self { self.reverse_bits() }
+            fn to_be(self) -> Self { self.to_be() }
+            fn to_le(self) -> Self { self.to_le() }
+            fn wrapping_neg(self) -> Self { self.wrapping_neg() }
+            fn pow(self, exp: u32) -> Self { self.pow(exp) }
+            fn wrapping_add(self, rhs: Self) -> Self { self.wrapping_add(rhs) }
+            fn wrapping_sub(self, rhs: Self) -> Self { self.wrapping_sub(rhs) }
+            fn wrapping_mul(self, rhs: Self) -> Self { self.wrapping_mul(rhs) }
+            fn saturating_add(self, rhs: Self) -> Self { self.saturating_add(rhs) }
+            fn saturating_sub(self, rhs: Self) -> Self { self.saturating_sub(rhs) }
+            fn saturating_mul(self, rhs: Self) -> Self { self.saturating_mul(rhs) }
+            fn abs_diff(self, rhs: Self) -> $u { self.abs_diff(rhs) }
+        }
+        
+        impl Euclid for $t {
+            fn div_euclid(self, rhs: Self) -> Self { self.div_euclid(rhs) }
+            fn rem_euclid(self, rhs: Self) -> Self { self.rem_euclid(rhs) }
+        }
+    )*
+}
+
+macro impl_signed_int($($t: ident),*) {
+    $(
+        impl SignedInt for $t {
+            type Bool = bool;
+            fn signum(self) -> Self { self.signum() }
+            fn wrapping_abs(self) -> Self { self.wrapping_abs() }
+            fn unsigned_abs(self) -> Self::Unsigned { self.unsigned_abs() }
+            fn is_positive(self) -> bool { self.is_positive() }
+            fn is_negative(self) -> bool { self.is_negative() }
+        }
+    )*
+}
+
+impl_float!(f32,f64);
+impl_int!(u8,u8; u16,u16; u32,u32; u64,u64; usize,usize; i8,u8; i16,u16; i32,u32; i64,u64; isize,usize);
+impl_signed_int!(i8,i16,i32,i64,isize);
+
+//implements Trig, Sqrt, Float, Int, SignedInt and Euclid component-wise for a vector type with map and zip_map
+//the brackets hold any extra generic params, e.g. impl_component_wise!([, const N: usize] VecN [, N])
+#[macro_export]
+macro_rules! impl_component_wise {
+([$($gen: tt)*] $type: ident [$($args: tt)*]) => {
+    impl<T: Copy + Trig $($gen)*> Trig for $type<T $($args)*> {
+        fn sin(self) -> Self { self.map(T::sin) }
+        fn cos(self) -> Self { self.map(T::cos) }
+        fn tan(self) -> Self { self.map(T::tan) }
+        fn asin(self) -> Self { self.map(T::asin) }
+        fn acos(self) -> Self { self.map(T::acos) }
+        fn atan(self) -> Self { self.map(T::atan) }
+        fn atan2(self, other: Self) -> Self { self.zip_map(other, T::atan2) }
+        fn sin_cos(self) -> (Self, Self) { (self.map(T::sin), self.map(T::cos)) }
+        fn sinh(self) -> Self { self.map(T::sinh) }
+        fn cosh(self) -> Self { self.map(T::cosh) }
+        fn tanh(self) -> Self { self.map(T::tanh) }
+        fn asinh(self) -> Self { self.map(T::asinh) }
+        fn acosh(self) -> Self { self.map(T::acosh) }
+        fn atanh(self) -> Self { self.map(T::atanh) }
+    }
+    
+    impl<T: Copy + Sqrt<T> $($gen)*> Sqrt<Self> for $type<T $($args)*> {
+        fn sqrt(self) -> Self { self.map(T::sqrt) }
+    }
+    
+    impl<T: Float $($gen)*> Float for $type<T $($args)*> {
+        type Bool = $type<T::Bool $($args)*>;
+        fn floor(self) -> Self { self.map(T::floor) }
+        fn ceil(self) -> Self { self.map(T::ceil) }
+        fn round(self) -> Self { self.map(T::round) }
+        fn trunc(self) -> Self { self.map(T::trunc) }
+        fn fract(self) -> Self { self.map(T::fract) }
+        fn signum(self) -> Self { self.map(T::signum) }
+        fn recip(self) -> Self { self.map(T::recip) }
+        fn exp(self) -> Self { self.map(T::exp) }
+        fn exp2(self) -> Self { self.map(T::exp2) }
+        fn exp_m1(self) -> Self { self.map(T::exp_m1) }
+        fn ln(self) -> Self { self.map(T::ln) }
+        fn ln_1p(self) -> Self { self.map(T::ln_1p) }
+        fn log2(self) -> Self { self.map(T::log2) }
+        fn log10(self) -> Self { self.map(T::log10) }
+        fn cbrt(self) -> Self { self.map(T::cbrt) }
+        fn to_degrees(self) -> Self { self.map(T::to_degrees) }
+        fn to_radians(self) -> Self { self.map(T::to_radians) }
+        fn powi(self, n: i32) -> Self { self.map(|x| x.powi(n)) }
+        fn powf(self, n: Self) -> Self { self.zip_map(n, T::powf) }
+        fn log(self, base: Self) -> Self { self.zip_map(base, T::log) }
+        fn hypot(self, other: Self) -> Self { self.zip_map(other, T::hypot) }
+        fn copysign(self, sign: Self) -> Self { self.zip_map(sign, T::copysign) }
+        fn mul_add(self, a: Self, b: Self) -> Self { self.zip_map(a, |x, a| (x, a)).zip_map(b, |(x, a), b| x.mul_add(a, b)) }
+        fn is_infinite(self) -> Self::Bool { self.map(T::is_infinite) }
+        fn is_finite(self) -> Self::Bool { self.map(T::is_finite) }
+        fn is_normal(self) -> Self::Bool { self.map(T::is_normal) }
+        fn is_sign_positive(self) -> Self::Bool { self.map(T::is_sign_positive) }
+        fn is_sign_negative(self) -> Self::Bool { self.map(T::is_sign_negative) }
+    }
+    
+    impl<T: Int $($gen)*> Int for $type<T $($args)*> {
+        type U32 = $type<T::U32 $($args)*>;
+        type Unsigned = $type<T::Unsigned $($args)*>;
+        fn count_ones(self) -> Self::U32 { self.map(T::count_ones) }
+        fn count_zeros(self) -> Self::U32 { self.map(T::count_zeros) }
+        fn leading_zeros(self) -> Self::U32 { self.map(T::leading_zeros) }
+        fn trailing_zeros(self) -> Self::U32 { self.map(T::trailing_zeros) }
+        fn leading_ones(self) -> Self::U32 { self.map(T::leading_ones) }
+        fn trailing_ones(self) -> Self::U32 { self.map(T::trailing_ones) }
+        fn swap_bytes(self) -> Self { self.map(T::swap_bytes) }
+        fn reverse_bits(self) -> Self { self.map(T::reverse_bits) }
+        fn to_be(self) -> Self { self.map(T::to_be) }
+        fn to_le(self) -> Self { self.map(T::to_le) }
+        fn wrapping_neg(self) -> Self { self.map(T::wrapping_neg) }
+        fn pow(self, exp: u32) -> Self { self.map(|x| x.pow(exp)) }
+        fn wrapping_add(self, rhs: Self) -> Self { self.zip_map(rhs, T::wrapping_add) }
+        fn wrapping_sub(self, rhs: Self) -> Self { self.zip_map(rhs, T::wrapping_sub) }
+        fn wrapping_mul(self, rhs: Self) -> Self { self.zip_map(rhs, T::wrapping_mul) }
+        fn saturating_add(self, rhs: Self) -> Self { self.zip_map(rhs, T::saturating_add) }
+        fn saturating_sub(self, rhs: Self) -> Self { self.zip_map(rhs, T::saturating_sub) }
+        fn saturating_mul(self, rhs: Self) -> Self { self.zip_map(rhs, T::saturating_mul) }
+        fn abs_diff(self, rhs: Self) -> Self::Unsigned { self.zip_map(rhs, T::abs_diff) }
+    }
+    
+    impl<T: SignedInt $($gen)*> SignedInt for $type<T $($args)*> {
+        type Bool = $type<<T as SignedInt>::Bool $($args)*>;
+        fn signum(self) -> Self { self.map(T::signum) }
+        fn wrapping_abs(self) -> Self { self.map(T::wrapping_abs) }
+        fn unsigned_abs(self) -> Self::Unsigned { self.map(T::unsigned_abs) }
+        fn is_positive(self) -> Self::Bool { self.map(T::is_positive) }
+        fn is_negative(self) -> Self::Bool { self.map(T::is_negative) }
+    }
+    
+    impl<T: Euclid $($gen)*> Euclid for $type<T $($args)*> {
+        fn div_euclid(self, rhs: Self) -> Self { self.zip_map(rhs, T::div_euclid) }
+        fn rem_euclid(self, rhs: Self) -> Self { self.zip_map(rhs, T::rem_euclid) }
+    }
+};
+//inherent versions of the methods Vec2/Vec3/Vec4 had before the traits existed, so code that doesn't import them still compiles
+(inherent $type: ident) => {
+    $crate::impl_component_wise!(inherent_float $type f32 f64);
+    $crate::impl_component_wise!(inherent_int $type i8 i16 i32 i64 isize);
+};
+(inherent_float $type: ident $($t: ident)*) => {$(
+    impl $type<$t> {
+        pub fn floor(self) -> Self { Float::floor(self) }
+        pub fn ceil(self) -> Self { Float::ceil(self) }
+        pub fn round(self) -> Self { Float::round(self) }
+        pub fn trunc(self) -> Self { Float::trunc(self) }
+        pub fn fract(self) -> Self { Float::fract(self) }
+        pub fn signum(self) -> Self { Float::signum(self) }
+        pub fn sqrt(self) -> Self { Sqrt::sqrt(self) }
+        pub fn exp(self) -> Self { Float::exp(self) }
+        pub fn exp2(self) -> Self { Float::exp2(self) }
+        pub fn ln(self) -> Self { Float::ln(self) }
+        pub fn log2(self) -> Self { Float::log2(self) }
+        pub fn log10(self) -> Self { Float::log10(self) }
+        pub fn cbrt(self) -> Self { Float::cbrt(self) }
+        pub fn exp_m1(self) -> Self { Float::exp_m1(self) }
+        pub fn ln_1p(self) -> Self { Float::ln_1p(self) }
+        pub fn is_infinite(self) -> $type<bool> { Float::is_infinite(self) }
+        pub fn is_finite(self) -> $type<bool> { Float::is_finite(self) }
+        pub fn is_normal(self) -> $type<bool> { Float::is_normal(self) }
+        pub fn is_sign_positive(self) -> $type<bool> { Float::is_sign_positive(self) }
+        pub fn is_sign_negative(self) -> $type<bool> { Float::is_sign_negative(self) }
+    }
+)*};
+(inherent_int $type: ident $($t: ident)*) => {$(
+    impl $type<$t> {
+        pub fn signum(self) -> Self { SignedInt::signum(self) }
+        pub fn swap_bytes(self) -> Self { Int::swap_bytes(self) }
+        pub fn to_be(self) -> Self { Int::to_be(self) }
+        pub fn to_le(self) -> Self { Int::to_le(self) }
+        pub fn wrapping_neg(self) -> Self { Int::wrapping_neg(self) }
+        pub fn wrapping_abs(self) -> Self { SignedInt::wrapping_abs(self) }
+        pub fn is_positive(self) -> $type<bool> { SignedInt::is_positive(self) }
+        pub fn is_negative(self) -> $type<bool> { SignedInt::is_negative(self) }
+    }
+)*};
+}
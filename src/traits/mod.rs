@@ -2,6 +2,8 @@ use std::marker::Sized;
 
 mod numbers;
 pub use numbers::*;
+mod component_wise;
+pub use component_wise::*;
 
 pub trait Sqrt<T> {
     fn sqrt(self) -> T;
@@ -1,3 +1,5 @@
+use std::ops::*;
+
 pub trait Zero {
     fn zero() -> Self;
 }
@@ -10,6 +12,13 @@ pub trait Two {
 pub trait Abs {
     fn abs(self) -> Self;
 }
+pub trait Epsilon {
+    fn epsilon() -> Self;
+}
+//division that only succeeds if nothing is lost, floats always succeed
+pub trait ExactDiv: Sized {
+    fn exact_div(self, rhs: Self) -> Option<Self>;
+}
 
 macro impl_ints($($t: ty),*) {
     $(
@@ -25,6 +34,14 @@ macro impl_ints($($t: ty),*) {
         impl Abs for $t {
             fn abs(self) -> Self { self.abs_diff(0) as $t }
         }
+        impl Epsilon for $t {
+            fn epsilon() -> Self { 0 }
+        }
+        impl ExactDiv for $t {
+            fn exact_div(self, rhs: Self) -> Option<Self> {
+                if self.checked_rem(rhs)? == 0 { self.checked_div(rhs) } else { None }
+            }
+        }
     )*
 }
 
@@ -42,9 +59,19 @@ macro impl_floats($($t: ident),*) {
         impl Abs for $t {
             fn abs(self) -> Self { self.abs() }
         }
+        impl Epsilon for $t {
+            fn epsilon() -> Self { $t::EPSILON }
+        }
+        impl ExactDiv for $t {
+            fn exact_div(self, rhs: Self) -> Option<Self> { Some(self / rhs) }
+        }
     )*
 }
 
+//shorthand for the bounds needed by the decompositions in linalg
+pub trait Scalar: Copy + PartialOrd + Zero + One + Abs + Epsilon + Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Div<Output=Self> + Neg<Output=Self> {}
+impl<T: Copy + PartialOrd + Zero + One + Abs + Epsilon + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T>> Scalar for T {}
+
 impl_ints!(u8,u16,u32,u64,usize,i8,i16,i32,i64,isize);
 impl_floats!(f32,f64);
 
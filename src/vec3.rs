@@ -2,7 +2,7 @@ use crate::prelude::*;
 use crate::vec2::*;
 use crate::vec4::*;
 
-pub use crate::prelude::{dot,distance,distance_squared,orthog_dist,angle_between,Zero,One,Abs,VecOps};
+pub use crate::prelude::{dot,distance,distance_squared,orthog_dist,angle_between,Zero,One,Abs,VecOps,Trig,Sqrt,Float,Int,SignedInt,Euclid};
 
 #[repr(C)]
 #[cfg_attr(feature="serde", derive(Serialize,Deserialize))]
@@ -49,103 +49,6 @@ pub fn cross<T: Copy + Mul<Output=T> + Sub<Output=T>>(v: Vec3<T>, u: Vec3<T>) ->
         v.x * u.y - v.y * u.x
     )
 }
-//TODO: generalize these to all VecN
-macro impl_ints1($($U: ident),*) {
-    $(
-        impl Vec3<isize> {
-            pub fn $U(self) -> Self {
-                vec3(self.x.$U(), self.y.$U(), self.z.$U())
-            }
-        }
-        impl Vec3<i64> {
-            pub fn $U(self) -> Self {
-                vec3(self.x.$U(), self.y.$U(), self.z.$U())
-            }
-        }
-        impl Vec3<i32> {
-            pub fn $U(self) -> Self {
-                vec3(self.x.$U(), self.y.$U(), self.z.$U())
-            }
-        }
-        impl Vec3<i16> {
-            pub fn $U(self) -> Self {
-                vec3(self.x.$U(), self.y.$U(), self.z.$U())
-            }
-        }
-        impl Vec3<i8> {
-            pub fn $U(self) -> Self {
-                vec3(self.x.$U(), self.y.$U(), self.z.$U())
-            }
-        }
-    )*
-}
-
-macro impl_ints2($($U: ident),*) {
-    $(
-        impl Vec3<isize> {
-            pub fn $U(self) -> Vec3<bool> {
-                vec3(self.x.$U(), self.y.$U(), self.z.$U())
-            }
-        }
-        impl Vec3<i64> {
-            pub fn $U(self) -> Vec3<bool> {
-                vec3(self.x.$U(), self.y.$U(), self.z.$U())
-            }
-        }
-        impl Vec3<i32> {
-            pub fn $U(self) -> Vec3<bool> {
-                vec3(self.x.$U(), self.y.$U(), self.z.$U())
-            }
-        }
-        impl Vec3<i16> {
-            pub fn $U(self) -> Vec3<bool> {
-                vec3(self.x.$U(), self.y.$U(), self.z.$U())
-            }
-        }
-        impl Vec3<i8> {
-            pub fn $U(self) -> Vec3<bool> {
-                vec3(self.x.$U(), self.y.$U(), self.z.$U())
-            }
-        }
-    )*
-}
-
-macro impl_floats1($($U: ident),*) {
-    $(
-        impl Vec3<f64> {
-            pub fn $U(self) -> Self {
-                vec3(self.x.$U(), self.y.$U(), self.z.$U())
-            }
-        }
-        impl Vec3<f32> {
-            pub fn $U(self) -> Self {
-                vec3(self.x.$U(), self.y.$U(), self.z.$U())
-            }
-        }
-    )*
-}
-
-macro impl_floats2($($U: ident),*) {
-    $(
-        impl Vec3<f64> {
-            pub fn $U(self) -> Vec3<bool> {
-                vec3(self.x.$U(), self.y.$U(), self.z.$U())
-            }
-        }
-        impl Vec3<f32> {
-            pub fn $U(self) -> Vec3<bool> {
-                vec3(self.x.$U(), self.y.$U(), self.z.$U())
-            }
-        }
-    )*
-}
-
-//component-wise functions
-//certain conversion and trig functions not implemented to avoid confusion
-impl_ints1!(signum,swap_bytes/*,reverse_bits*/,to_be,to_le,wrapping_neg,wrapping_abs);
-impl_ints2!(is_positive,is_negative);
-impl_floats1!(floor,ceil,round,trunc,fract,signum,sqrt,exp,exp2,ln,log2,log10,cbrt,exp_m1,ln_1p);
-impl_floats2!(is_infinite,is_finite,is_normal,is_sign_positive,is_sign_negative);
 
 impl<T: fmt::Display> fmt::Display for Vec3<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
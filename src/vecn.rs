@@ -0,0 +1,346 @@
+use crate::prelude::*;
+use crate::vec2::*;
+use crate::vec3::*;
+use crate::vec4::*;
+
+pub use crate::prelude::{dot,distance,distance_squared,orthog_dist,angle_between,Zero,One,Abs,VecOps,Trig,Sqrt,Float,Int,SignedInt,Euclid};
+
+//arbitrary length counterpart of Vec2/Vec3/Vec4 with the same operators as impl_vec!
+//serde only implements its traits for arrays up to length 32, so there is no serde derive here
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct VecN<T, const N: usize>(pub [T; N]);
+
+pub const fn vecn<T, const N: usize>(a: [T; N]) -> VecN<T, N> {
+    VecN(a)
+}
+
+impl<T, const N: usize> From<[T; N]> for VecN<T, N> {
+    fn from(a: [T; N]) -> Self {
+        VecN(a)
+    }
+}
+impl<T, const N: usize> From<VecN<T, N>> for [T; N] {
+    fn from(v: VecN<T, N>) -> Self {
+        v.0
+    }
+}
+impl<T> From<Vec2<T>> for VecN<T, 2> {
+    fn from(v: Vec2<T>) -> Self {
+        VecN(v.into())
+    }
+}
+impl<T> From<VecN<T, 2>> for Vec2<T> {
+    fn from(v: VecN<T, 2>) -> Self {
+        v.0.into()
+    }
+}
+impl<T> From<Vec3<T>> for VecN<T, 3> {
+    fn from(v: Vec3<T>) -> Self {
+        VecN(v.into())
+    }
+}
+impl<T> From<VecN<T, 3>> for Vec3<T> {
+    fn from(v: VecN<T, 3>) -> Self {
+        v.0.into()
+    }
+}
+impl<T> From<Vec4<T>> for VecN<T, 4> {
+    fn from(v: Vec4<T>) -> Self {
+        VecN(v.into())
+    }
+}
+impl<T> From<VecN<T, 4>> for Vec4<T> {
+    fn from(v: VecN<T, 4>) -> Self {
+        v.0.into()
+    }
+}
+
+impl<T: Default, const N: usize> Default for VecN<T, N> {
+    fn default() -> Self {
+        VecN(std::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T: Copy + Zero + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Abs, const N: usize> VecOps<T> for VecN<T, N> {
+    fn sum_elem(self) -> T {
+        self.0.iter().fold(T::zero(), |a, &b| a + b)
+    }
+}
+
+impl<T, const N: usize> VecN<T, N> {
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> VecN<U, N> {
+        VecN(self.0.map(f))
+    }
+    
+    pub fn zip_map<U, V>(self, rhs: VecN<U, N>, mut f: impl FnMut(T, U) -> V) -> VecN<V, N> {
+        let mut rhs = IntoIterator::into_iter(rhs.0);
+        VecN(self.0.map(|x| f(x, rhs.next().unwrap())))
+    }
+}
+
+impl_component_wise!([, const N: usize] VecN [, N]);
+
+impl<T: Copy, const N: usize> VecN<T, N> {
+    //the x axis, or empty if N is 0
+    pub fn ident() -> Self
+        where T: Zero + One {
+        let mut r = Self::zero();
+        if let Some(x) = r.0.first_mut() {
+            *x = T::one();
+        }
+        r
+    }
+    
+    pub fn mul_elem(self) -> T
+        where T: One + Mul<Output=T> {
+        self.0.iter().fold(T::one(), |a, &b| a * b)
+    }
+    
+    pub fn min(self, rhs: Self) -> Self
+        where T: IsNan {
+        self.zip_map(rhs, T::non_nan_min)
+    }
+    
+    pub fn max(self, rhs: Self) -> Self
+        where T: IsNan {
+        self.zip_map(rhs, T::non_nan_max)
+    }
+    
+    pub fn clamp(self, min: Self, max: Self) -> Self
+        where T: IsNan {
+        self.min(max).max(min)
+    }
+    
+    //min_elem and max_elem panic if N is 0
+    pub fn min_elem(self) -> T
+        where T: IsNan {
+        let mut iter = self.0.iter();
+        let mut r = *iter.next().unwrap();
+        for &x in iter {
+            r = r.non_nan_min(x);
+        }
+        r
+    }
+    
+    pub fn max_elem(self) -> T
+        where T: IsNan {
+        let mut iter = self.0.iter();
+        let mut r = *iter.next().unwrap();
+        for &x in iter {
+            r = r.non_nan_max(x);
+        }
+        r
+    }
+    
+    pub fn exact_div(self, rhs: T) -> Option<Self>
+        where T: ExactDiv {
+        let mut r = self;
+        for x in r.0.iter_mut() {
+            *x = x.exact_div(rhs)?;
+        }
+        Some(r)
+    }
+    
+    pub fn is_nan(self) -> VecN<bool, N>
+        where T: IsNan {
+        self.map(|x| x.is_nan())
+    }
+    
+    pub fn magnitude(self) -> T
+        where T: Sqrt<T>, Self: VecOps<T> {
+        self.dot(self).sqrt()
+    }
+    
+    pub fn normalize(self) -> Self
+        where T: Div<Output=T> + Sqrt<T>, Self: VecOps<T> {
+        normalize(self)
+    }
+    
+    pub fn normalize_or_zero(self) -> Self
+        where T: Zero + Div<Output=T> + Sqrt<T> + IsNan, Self: VecOps<T> {
+        let r = self.normalize();
+        if r.is_nan().or() { Self::zero() } else { r }
+    }
+}
+
+impl<const N: usize> VecN<bool, N> {
+    pub fn or(self) -> bool {
+        self.0.iter().any(|&x| x)
+    }
+    
+    pub fn and(self) -> bool {
+        self.0.iter().all(|&x| x)
+    }
+}
+
+macro ops($($Op: ident, $op: ident, $OpAssign: ident, $op_assign: ident);*) {
+    $(
+        impl<T: Copy + $Op<Output=T>, const N: usize> $Op for VecN<T, N> {
+            type Output = Self;
+            fn $op(self, rhs: Self) -> Self {
+                self.zip_map(rhs, T::$op)
+            }
+        }
+        
+        impl<T: Copy + $OpAssign, const N: usize> $OpAssign for VecN<T, N> {
+            fn $op_assign(&mut self, rhs: Self) {
+                for (a, b) in self.0.iter_mut().zip(rhs.0) {
+                    a.$op_assign(b);
+                }
+            }
+        }
+        
+        impl<T: Copy + $Op<Output=T>, const N: usize> $Op<T> for VecN<T, N> {
+            type Output = Self;
+            fn $op(self, rhs: T) -> Self {
+                self.map(|x| x.$op(rhs))
+            }
+        }
+        
+        impl<T: Copy + $OpAssign, const N: usize> $OpAssign<T> for VecN<T, N> {
+            fn $op_assign(&mut self, rhs: T) {
+                for a in self.0.iter_mut() {
+                    a.$op_assign(rhs);
+                }
+            }
+        }
+    )*
+}
+
+ops!(
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Rem, rem, RemAssign, rem_assign
+);
+
+impl<T: Neg, const N: usize> Neg for VecN<T, N> {
+    type Output = VecN<<T as Neg>::Output, N>;
+    fn neg(self) -> Self::Output {
+        VecN(self.0.map(|x| -x))
+    }
+}
+
+impl<T: Abs, const N: usize> Abs for VecN<T, N> {
+    fn abs(self) -> Self {
+        VecN(self.0.map(|x| x.abs()))
+    }
+}
+
+impl<T: Zero, const N: usize> Zero for VecN<T, N> {
+    fn zero() -> Self {
+        VecN(std::array::from_fn(|_| T::zero()))
+    }
+}
+
+impl<T: One, const N: usize> One for VecN<T, N> {
+    fn one() -> Self {
+        VecN(std::array::from_fn(|_| T::one()))
+    }
+}
+
+impl<T: Copy + One + Mul<Output=T>, const N: usize> Product for VecN<T, N> {
+    fn product<I: Iterator<Item=Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |a, b| a * b)
+    }
+}
+
+impl<T: Copy + Zero + Add<Output=T>, const N: usize> Sum for VecN<T, N> {
+    fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
+
+impl<T, const N: usize> Index<usize> for VecN<T, N> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        &self.0[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for VecN<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.0[index]
+    }
+}
+
+impl<T: fmt::Display, const N: usize> fmt::Display for VecN<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, x) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            if let Some(p) = f.precision() {
+                write!(f, "{:.*}", p, x)?;
+            } else {
+                write!(f, "{}", x)?;
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+impl<T: NiceFmt, const N: usize> NiceFmt for VecN<T, N> {
+    fn nice_fmt(&self, limit: usize, pad: bool) -> String {
+        format!("({})", self.0.iter().map(|x| x.nice_fmt(limit, pad)).collect::<Vec<_>>().join(", "))
+    }
+}
+
+impl<T: fmt::LowerExp, const N: usize> fmt::LowerExp for VecN<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, x) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            if let Some(p) = f.precision() {
+                write!(f, "{:.*e}", p, x)?;
+            } else {
+                write!(f, "{:e}", x)?;
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+impl<T: FromStr, const N: usize> FromStr for VecN<T, N> {
+    type Err = <T as FromStr>::Err;
+    
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut words: Vec<&str> = input.split(|c: char| c.is_whitespace() || c == ',')
+            .map(|s| s.trim_matches(BRACKETS)).filter(|s| !s.is_empty()).collect();
+        while words.len() < N { words.push(""); }
+        let mut r: [Option<T>; N] = std::array::from_fn(|_| None);
+        for (x, word) in r.iter_mut().zip(words) {
+            *x = Some(word.parse()?);
+        }
+        Ok(VecN(r.map(Option::unwrap)))
+    }
+}
+
+macro convert($T: ty, $($U: ident),*) {
+    $(
+        impl<const N: usize> VecN<$T, N> {
+            pub fn $U(self) -> VecN<$U, N> {
+                self.map(|x| x as $U)
+            }
+        }
+    )*
+}
+
+convert!(u8,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
+convert!(u16,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
+convert!(u32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
+convert!(u64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
+convert!(usize,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
+convert!(i8,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
+convert!(i16,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
+convert!(i32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
+convert!(i64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
+convert!(isize,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
+convert!(f32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
+convert!(f64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
+convert!(bool,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize);
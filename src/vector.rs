@@ -53,6 +53,19 @@ macro_rules! impl_vec {
         }
     }
     
+    impl<T> $type<T> {
+        pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> $type<U> {
+            $vec($(f(self.$field),)*)
+        }
+        
+        pub fn zip_map<U, V>(self, rhs: $type<U>, mut f: impl FnMut(T, U) -> V) -> $type<V> {
+            $vec($(f(self.$field, rhs.$field),)*)
+        }
+    }
+    
+    impl_component_wise!([] $type []);
+    impl_component_wise!(inherent $type);
+    
     impl<T: Copy> $type<T> {
         pub fn ident() -> Self
             where T: Zero + One {
@@ -103,6 +116,11 @@ macro_rules! impl_vec {
             r
         }
         
+        pub fn exact_div(self, rhs: T) -> Option<Self>
+            where T: ExactDiv {
+            Some($vec($(self.$field.exact_div(rhs)?,)*))
+        }
+        
         pub fn is_nan(self) -> $type<bool>
             where T: IsNan {
             $vec($(self.$field.is_nan(),)*)
@@ -0,0 +1,67 @@
+use std::convert::TryFrom;
+use math_lib::vec2::*;
+use math_lib::vec3::*;
+use math_lib::vec4::*;
+use math_lib::mat2::*;
+use math_lib::mat3::*;
+use math_lib::mat4::*;
+use math_lib::affine2::*;
+use math_lib::affine3::*;
+use math_lib::error::*;
+
+mod common;
+use common::*;
+
+fn rnd_rotation(seed: &mut u64) -> Mat3<f64> {
+    rnd_quaternion(seed).normalize().rot_mat()
+}
+
+#[test]
+fn affine3_transform() {
+    let mut seed = 1;
+    for _ in 0..1000 {
+        let a = Affine3 { linear: rnd_rotation(&mut seed), translation: rnd_vec3(&mut seed) };
+        let b = Affine3 { linear: mat3(rnd_vec3(&mut seed), rnd_vec3(&mut seed), rnd_vec3(&mut seed)), translation: rnd_vec3(&mut seed) };
+        let p = rnd_vec3(&mut seed);
+        assert!(approx_eq((a * b).transform_point(p), a.transform_point(b.transform_point(p))));
+        assert!(approx_eq(Mat4::from(a * b).transform_point3(p), (Mat4::from(a) * Mat4::from(b)).transform_point3(p)));
+        assert!(approx_eq(a.transform_vector(p), a.linear.apply_to(p)));
+        //the transpose shortcut and the general inverse
+        assert!(a.is_orthonormal(16.0 * f64::EPSILON));
+        assert!(approx_eq(a.inverse().transform_point(a.transform_point(p)), p));
+        assert!(approx_eq(a.inverse_orthonormal().transform_point(a.transform_point(p)), p));
+        if b.linear.det().abs() > 1e-3 {
+            assert!(approx_eq(b.inverse().transform_point(b.transform_point(p)), p));
+        }
+        assert_eq!(Affine3::try_from(Mat4::from(b)), Ok(b));
+    }
+    //the tolerance is up to the caller
+    let scaled = Affine3::from_linear(mat3(vec3(1.0 + 1e-6, 0.0, 0.0), vec3(0.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0)));
+    assert!(!scaled.is_orthonormal(1e-9) && scaled.is_orthonormal(1e-5));
+    let mut m = Mat4::from(Affine3::from_translation(vec3(1.0, 2.0, 3.0)));
+    m.w = vec4(0.0, 0.0, 1.0, 1.0);
+    assert_eq!(Affine3::try_from(m), Err(NotAffine));
+}
+
+#[test]
+fn affine2_transform() {
+    let mut seed = 2;
+    for _ in 0..1000 {
+        let t = vec2(rnd(&mut seed), rnd(&mut seed));
+        let a = affine2(Mat2::rotate(rnd(&mut seed) * 3.0), t);
+        let b = affine2(mat2(vec2(rnd(&mut seed), rnd(&mut seed)), vec2(rnd(&mut seed), rnd(&mut seed))), t * 2.0);
+        let p = vec2(rnd(&mut seed), rnd(&mut seed));
+        assert!(approx_eq((a * b).transform_point(p), a.transform_point(b.transform_point(p))));
+        assert!(approx_eq(Mat3::from(a * b).transform_point2(p), (Mat3::from(a) * Mat3::from(b)).transform_point2(p)));
+        assert!(a.is_orthonormal(16.0 * f64::EPSILON));
+        assert!(approx_eq(a.inverse().transform_point(a.transform_point(p)), p));
+        if b.linear.det().abs() > 1e-3 {
+            assert!(approx_eq(b.inverse().transform_point(b.transform_point(p)), p));
+        }
+        assert_eq!(Affine2::try_from(Mat3::from(b)), Ok(b));
+    }
+    //exact for integers
+    let a = affine2(mat2(vec2(0, -1), vec2(1, 0)), vec2(3, 4));
+    assert_eq!(a.inverse() * a, Affine2::ident());
+    assert_eq!(Affine2::try_from(mat3(vec3(1, 0, 0), vec3(0, 1, 0), vec3(1, 0, 1))), Err(NotAffine));
+}
@@ -0,0 +1,74 @@
+//helpers shared by the integration tests, each test crate only uses some of them
+#![allow(dead_code)]
+
+use math_lib::vec2::*;
+use math_lib::vec3::*;
+use math_lib::vec4::*;
+use math_lib::mat3::*;
+use math_lib::mat4::*;
+use math_lib::quaternion::*;
+
+//small lcg so the tests don't need a rand dependency, uniform in [-1, 1)
+pub fn rnd(seed: &mut u64) -> f64 {
+    *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    (*seed >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+}
+
+//in -n..=n
+pub fn rnd_int(seed: &mut u64, n: i64) -> i64 {
+    *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    (*seed >> 33) as i64 % (2 * n + 1) - n
+}
+
+pub fn rnd_vec3(seed: &mut u64) -> Vec3<f64> {
+    vec3(rnd(seed), rnd(seed), rnd(seed))
+}
+
+pub fn rnd_quaternion(seed: &mut u64) -> Quaternion<f64> {
+    quaternion(rnd(seed), rnd(seed), rnd(seed), rnd(seed))
+}
+
+//every component within 1e-9
+pub trait ApproxEq {
+    fn approx_eq(self, other: Self) -> bool;
+}
+
+pub fn approx_eq<T: ApproxEq>(a: T, b: T) -> bool {
+    a.approx_eq(b)
+}
+
+impl ApproxEq for Vec2<f64> {
+    fn approx_eq(self, other: Self) -> bool {
+        (self - other).abs().max_elem() < 1e-9
+    }
+}
+
+impl ApproxEq for Vec3<f64> {
+    fn approx_eq(self, other: Self) -> bool {
+        (self - other).abs().max_elem() < 1e-9
+    }
+}
+
+impl ApproxEq for Vec4<f64> {
+    fn approx_eq(self, other: Self) -> bool {
+        (self - other).abs().max_elem() < 1e-9
+    }
+}
+
+impl ApproxEq for Quaternion<f64> {
+    fn approx_eq(self, other: Self) -> bool {
+        (0..4).all(|n| (self[n] - other[n]).abs() < 1e-9)
+    }
+}
+
+impl ApproxEq for Mat3<f64> {
+    fn approx_eq(self, other: Self) -> bool {
+        self.x.approx_eq(other.x) && self.y.approx_eq(other.y) && self.z.approx_eq(other.z)
+    }
+}
+
+impl ApproxEq for Mat4<f64> {
+    fn approx_eq(self, other: Self) -> bool {
+        self.x.approx_eq(other.x) && self.y.approx_eq(other.y) && self.z.approx_eq(other.z) && self.w.approx_eq(other.w)
+    }
+}
@@ -0,0 +1,27 @@
+use math_lib::vec2::{vec2,Vec2};
+use math_lib::vec3::{vec3,Vec3};
+use math_lib::vecn::vecn;
+
+//no trait imports, so these go through the inherent methods that predate the traits
+#[test]
+fn inherent() {
+    assert_eq!(vec3(1.5f32, -0.5, 2.0).floor(), vec3(1.0, -1.0, 2.0));
+    assert_eq!(vec2(4.0f64, 9.0).sqrt(), vec2(2.0, 3.0));
+    assert_eq!(vec3(1.0f64, f64::INFINITY, 0.0).is_finite(), vec3(true, false, true));
+    assert_eq!(vec3(-3i32, 0, 7).signum(), vec3(-1, 0, 1));
+    assert_eq!(vec2(i8::MIN, -2).wrapping_abs(), vec2(i8::MIN, 2));
+    assert_eq!(Vec2::<i64>::is_negative(vec2(-1, 1)), vec2(true, false));
+    assert_eq!(Vec3::<f32>::signum(vec3(-2.0, 0.5, 3.0)), vec3(-1.0, 1.0, 1.0));
+}
+
+#[test]
+fn traits() {
+    use math_lib::vec3::{Float,Int,SignedInt,Euclid,Trig};
+    assert_eq!(Float::powi(vec3(2.0, 3.0, -1.0), 2), vec3(4.0, 9.0, 1.0));
+    assert_eq!(Float::mul_add(vec3(1.0, 2.0, 3.0), vec3(2.0, 2.0, 2.0), vec3(1.0, 0.0, -1.0)), vec3(3.0, 4.0, 5.0));
+    assert_eq!(Trig::sin_cos(vec3(0.0f64, 0.0, 0.0)), (vec3(0.0, 0.0, 0.0), vec3(1.0, 1.0, 1.0)));
+    assert_eq!(Int::count_ones(vec3(7u8, 0, 255)), vec3(3, 0, 8));
+    assert_eq!(Int::abs_diff(vec3(-5i32, 3, 0), vec3(5, -3, 0)), vec3(10u32, 6, 0));
+    assert_eq!(SignedInt::unsigned_abs(vecn([i16::MIN, 4])), vecn([32768u16, 4]));
+    assert_eq!(Euclid::rem_euclid(vec3(-7, 7, -1), vec3(3, 3, 5)), vec3(2, 1, 4));
+}
@@ -0,0 +1,74 @@
+use math_lib::dual_quaternion::*;
+use math_lib::vec3::*;
+
+mod common;
+use common::*;
+
+fn rnd_rigid(seed: &mut u64) -> DualQuaternion<f64> {
+    let rotation = rnd_quaternion(seed).normalize();
+    DualQuaternion::from_rotation_translation(rotation, rnd_vec3(seed) * 10.0)
+}
+
+#[test]
+fn transform() {
+    let mut seed = 1;
+    for _ in 0..1000 {
+        let (a, b) = (rnd_rigid(&mut seed), rnd_rigid(&mut seed));
+        let p = rnd_vec3(&mut seed);
+        assert!(approx_eq(a.transform_point(p), a.rotation().rotate(p) + a.translation()));
+        assert!(approx_eq((a * b).transform_point(p), a.transform_point(b.transform_point(p))));
+        assert!(approx_eq(a.inv().transform_point(a.transform_point(p)), p));
+        assert!(approx_eq(a.inv_unit().transform_point(a.transform_point(p)), p));
+        //scaling doesn't change the transform once normalized
+        assert!(approx_eq((a * 3.0).normalize().transform_point(p), a.transform_point(p)));
+    }
+}
+
+#[test]
+fn mat4_round_trip() {
+    let mut seed = 2;
+    for _ in 0..1000 {
+        let a = rnd_rigid(&mut seed);
+        let p = rnd_vec3(&mut seed);
+        let m = a.to_mat4();
+        assert!(approx_eq(m.transform_point3(p), a.transform_point(p)));
+        //q and -q are the same transform
+        let b = DualQuaternion::from_mat4(m);
+        assert!(approx_eq(b.transform_point(p), a.transform_point(p)));
+        assert!(approx_eq(b.translation(), a.translation()));
+    }
+}
+
+#[test]
+fn sclerp() {
+    let mut seed = 3;
+    for _ in 0..1000 {
+        let (a, b) = (rnd_rigid(&mut seed), rnd_rigid(&mut seed));
+        let p = rnd_vec3(&mut seed);
+        assert!(approx_eq(a.sclerp(b, 0.0).transform_point(p), a.transform_point(p)));
+        assert!(approx_eq(a.sclerp(b, 1.0).transform_point(p), b.transform_point(p)));
+        //constant speed: two half steps make the whole step
+        let half = a.sclerp(b, 0.5);
+        assert!(approx_eq((half * a.inv_unit() * half).transform_point(p), b.transform_point(p)));
+    }
+    //pure translations interpolate linearly
+    let a = DualQuaternion::from_translation(vec3(1.0, 2.0, 3.0));
+    let b = DualQuaternion::from_translation(vec3(5.0, -2.0, 3.0));
+    assert!(approx_eq(a.sclerp(b, 0.25).translation(), vec3(2.0, 1.0, 3.0)));
+}
+
+#[test]
+fn blend() {
+    let mut seed = 4;
+    for _ in 0..1000 {
+        let a = rnd_rigid(&mut seed);
+        let p = rnd_vec3(&mut seed);
+        //the same transform with opposite signs doesn't cancel out
+        let b = DualQuaternion::blend(vec![(a, 0.3), (-a, 0.7)]);
+        assert!(approx_eq(b.transform_point(p), a.transform_point(p)));
+    }
+    let a = DualQuaternion::from_translation(vec3(2.0, 0.0, 0.0));
+    let b = DualQuaternion::from_translation(vec3(0.0, 4.0, 0.0));
+    assert!(approx_eq(DualQuaternion::blend(vec![(a, 0.5), (b, 0.5)]).translation(), vec3(1.0, 2.0, 0.0)));
+    assert_eq!(DualQuaternion::<f64>::blend(vec![]), DualQuaternion::ident());
+}
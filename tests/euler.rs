@@ -0,0 +1,59 @@
+use std::f64::consts::{PI,FRAC_PI_2};
+use math_lib::vec3::*;
+use math_lib::mat3::*;
+use math_lib::quaternion::*;
+use math_lib::euler::*;
+use math_lib::euler::EulerAxes::*;
+
+mod common;
+use common::*;
+
+const AXES: [EulerAxes; 12] = [XYZ, XZY, YXZ, YZX, ZXY, ZYX, XYX, XZX, YXY, YZY, ZXZ, ZYZ];
+
+fn orders() -> impl Iterator<Item=EulerOrder> {
+    AXES.iter().flat_map(|&a| [EulerOrder::Intrinsic(a), EulerOrder::Extrinsic(a)])
+}
+
+#[test]
+fn intrinsic_extrinsic() {
+    let (a, b, c) = (0.3, -1.1, 2.5);
+    let (x, y, z) = (Mat3::rotate_x(a), Mat3::rotate_y(b), Mat3::rotate_z(c));
+    assert!(approx_eq(Mat3::from_euler(EulerOrder::Intrinsic(XYZ), vec3(a, b, c)), x * y * z));
+    assert!(approx_eq(Mat3::from_euler(EulerOrder::Extrinsic(XYZ), vec3(a, b, c)), z * y * x));
+    assert!(approx_eq(Mat3::from_euler(EulerOrder::Intrinsic(ZXZ), vec3(a, b, c)), Mat3::rotate_z(a) * Mat3::rotate_x(b) * Mat3::rotate_z(c)));
+    for order in orders() {
+        let q = Quaternion::from_euler(order, vec3(a, b, c));
+        assert!(approx_eq(q.rot_mat(), Mat3::from_euler(order, vec3(a, b, c))));
+    }
+}
+
+#[test]
+fn round_trip() {
+    let mut seed = 1;
+    for order in orders() {
+        let (axes, _) = order.to_intrinsic();
+        for _ in 0..200 {
+            let b = if axes.is_proper() { (rnd(&mut seed) + 1.0) * FRAC_PI_2 } else { rnd(&mut seed) * FRAC_PI_2 };
+            let angles = vec3(rnd(&mut seed) * PI, b, rnd(&mut seed) * PI);
+            let m = Mat3::from_euler(order, angles);
+            assert!((m.to_euler(order) - angles).abs().max_elem() < 1e-9, "{:?} {:?}", order, angles);
+            assert!((Quaternion::from_euler(order, angles).to_euler(order) - angles).abs().max_elem() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn gimbal_lock() {
+    for order in orders() {
+        let (axes, reversed) = order.to_intrinsic();
+        let locked: &[f64] = if axes.is_proper() { &[0.0, PI] } else { &[FRAC_PI_2, -FRAC_PI_2] };
+        for &b in locked {
+            let m = Mat3::from_euler(order, vec3(0.7, b, -0.4));
+            let angles = m.to_euler(order);
+            assert!((angles.y - b).abs() < 1e-9, "{:?} {:?}", order, angles);
+            //the outer angles aren't unique any more, but they still have to give back the same rotation
+            assert!(approx_eq(Mat3::from_euler(order, angles), m), "{:?} {:?}", order, angles);
+            assert_eq!(if reversed { angles.x } else { angles.z }, 0.0);
+        }
+    }
+}
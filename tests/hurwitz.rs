@@ -0,0 +1,91 @@
+use math_lib::hurwitz::*;
+use math_lib::quaternion::*;
+
+mod common;
+use common::*;
+
+//doubled components in -2n..=2n, all even or all odd
+fn rnd_hurwitz(seed: &mut u64, n: i64) -> Hurwitz<i64> {
+    let parity = rnd_int(seed, 1).abs();
+    let mut c = || rnd_int(seed, n) * 2 + parity;
+    Hurwitz::from_doubled(quaternion(c(), c(), c(), c())).unwrap()
+}
+
+fn is_prime(n: i64) -> bool {
+    n > 1 && (2..).take_while(|p| p * p <= n).all(|p| n % p != 0)
+}
+
+#[test]
+fn div_rem() {
+    let mut seed = 1;
+    for _ in 0..1000 {
+        let a = rnd_hurwitz(&mut seed, 50);
+        let b = rnd_hurwitz(&mut seed, 10);
+        if b.norm() == 0 {
+            continue;
+        }
+        let (q, r) = a.div_rem(b);
+        assert_eq!(q * b + r, a);
+        assert!(r.norm() < b.norm(), "{} {} {}", a, b, r);
+        assert_eq!((a * b).exact_div(b), Some(a));
+    }
+}
+
+#[test]
+fn gcd() {
+    let mut seed = 2;
+    for _ in 0..1000 {
+        let c = rnd_hurwitz(&mut seed, 5);
+        let a = rnd_hurwitz(&mut seed, 5) * c;
+        let b = rnd_hurwitz(&mut seed, 5) * c;
+        let g = a.gcd(b);
+        if g.norm() == 0 {
+            continue;
+        }
+        //a right divisor of both that the common factor divides
+        assert!(a.exact_div(g).is_some() && b.exact_div(g).is_some(), "{} {} {}", a, b, g);
+        if c.norm() != 0 {
+            assert!(g.exact_div(c).is_some());
+        }
+    }
+}
+
+#[test]
+fn factor() {
+    let mut seed = 3;
+    for _ in 0..300 {
+        let a = rnd_hurwitz(&mut seed, 20);
+        if a.norm() <= 1 {
+            continue;
+        }
+        let factors = a.factor();
+        assert_eq!(factors.iter().copied().reduce(|x, y| x * y), Some(a));
+        assert!(factors.iter().all(|f| is_prime(f.norm())), "{} {:?}", a, factors);
+    }
+    //units and 0 come back unchanged
+    let unit = Hurwitz::from_doubled(quaternion(1, -1, 1, 1)).unwrap();
+    assert_eq!(unit.factor(), vec![unit]);
+    assert_eq!(Hurwitz::new(quaternion(0, 0, 0, 0)).factor(), vec![Hurwitz::new(quaternion(0, 0, 0, 0))]);
+}
+
+#[test]
+fn div_rem_large() {
+    //the quotient is small, so nothing should come anywhere near overflowing
+    let a = Hurwitz::new(quaternion(20000i64, 3, 5, 7));
+    let b = Hurwitz::new(quaternion(20000i64, 1, 1, 1));
+    let (q, r) = a.div_rem(b);
+    assert_eq!(q, Hurwitz::new(quaternion(1, 0, 0, 0)));
+    assert_eq!(q * b + r, a);
+    let mut seed = 4;
+    for _ in 0..1000 {
+        let a = rnd_hurwitz(&mut seed, 10000);
+        let b = rnd_hurwitz(&mut seed, 10000);
+        if b.norm() == 0 {
+            continue;
+        }
+        let (q, r) = a.div_rem(b);
+        assert_eq!(q * b + r, a);
+        assert!(r.norm() < b.norm());
+        assert!(a.gcd(b).norm() > 0);
+    }
+}
@@ -0,0 +1,52 @@
+use math_lib::vec2::*;
+use math_lib::vec3::*;
+use math_lib::vec4::*;
+use math_lib::mat2::*;
+use math_lib::mat3::*;
+use math_lib::mat4::*;
+use math_lib::error::*;
+
+#[test]
+fn try_inv_mat2() {
+    //det 1, so the inverse is integral
+    let m = mat2(vec2(2i64, 1), vec2(1, 1));
+    let inv = m.try_inv(0).unwrap();
+    assert_eq!(inv, mat2(vec2(1, -1), vec2(-1, 2)));
+    assert_eq!(m * inv, Mat2::ident());
+    assert_eq!(mat2(vec2(2i64, 1), vec2(0, 1)).try_inv(0), Err(InvError::NonIntegral { det: 2 }));
+    assert_eq!(mat2(vec2(2i64, 1), vec2(4, 2)).try_inv(0), Err(InvError::Singular { det: 0 }));
+    //a float matrix whose determinant is only rounding error
+    let m = mat2(vec2(1.0, 1.0 + 1e-14), vec2(1.0, 1.0));
+    assert!(matches!(m.try_inv(1e-12), Err(InvError::Singular { .. })));
+    assert!(m.try_inv(0.0).is_ok());
+}
+
+#[test]
+fn try_inv_mat3() {
+    let m = mat3(vec3(1i64, 2, 3), vec3(0, 1, 4), vec3(5, 6, 0));
+    let inv = m.try_inv(0).unwrap();
+    assert_eq!(m * inv, Mat3::ident());
+    assert_eq!(mat3(vec3(2i64, 0, 0), vec3(0, 1, 0), vec3(0, 0, 3)).try_inv(0), Err(InvError::NonIntegral { det: 6 }));
+    assert_eq!(mat3(vec3(1i64, 2, 3), vec3(4, 5, 6), vec3(7, 8, 9)).try_inv(0), Err(InvError::Singular { det: 0 }));
+    let m = mat3(vec3(1.0, 2.0, 3.0), vec3(4.0, 5.0, 6.0), vec3(7.0, 8.0, 9.0 + 1e-13));
+    assert!(matches!(m.try_inv(1e-10), Err(InvError::Singular { .. })));
+    let m = mat3(vec3(2.0, 0.0, 0.0), vec3(0.0, 4.0, 0.0), vec3(0.0, 0.0, 0.5));
+    assert_eq!(m.try_inv(1e-10), Ok(mat3(vec3(0.5, 0.0, 0.0), vec3(0.0, 0.25, 0.0), vec3(0.0, 0.0, 2.0))));
+}
+
+#[test]
+fn try_inv_mat4() {
+    //upper triangular with ones on the diagonal
+    let m = mat4(vec4(1i64, 2, -1, 3), vec4(0, 1, 4, -2), vec4(0, 0, 1, 5), vec4(0, 0, 0, 1));
+    let inv = m.try_inv(0).unwrap();
+    assert_eq!(m * inv, Mat4::ident());
+    assert_eq!(inv * m, Mat4::ident());
+    let m = mat4(vec4(1i64, 2, -1, 3), vec4(0, 1, 4, -2), vec4(0, 0, 3, 5), vec4(0, 0, 0, 1));
+    assert_eq!(m.try_inv(0), Err(InvError::NonIntegral { det: 3 }));
+    let m = mat4(vec4(1i64, 2, -1, 3), vec4(2, 4, -2, 6), vec4(0, 0, 3, 5), vec4(0, 0, 0, 1));
+    assert_eq!(m.try_inv(0), Err(InvError::Singular { det: 0 }));
+    let m = mat4(vec4(1.0, 2.0, -1.0, 3.0), vec4(2.0, 4.0 + 1e-13, -2.0, 6.0), vec4(0.0, 0.0, 3.0, 5.0), vec4(0.0, 0.0, 0.0, 1.0));
+    assert!(matches!(m.try_inv(1e-10), Err(InvError::Singular { .. })));
+    let m = Mat4::from_scale(vec3(2.0, 4.0, 0.5));
+    assert_eq!(m.try_inv(1e-10), Ok(Mat4::from_scale(vec3(0.5, 0.25, 2.0))));
+}
@@ -0,0 +1,187 @@
+use math_lib::vec2::*;
+use math_lib::vec3::*;
+use math_lib::mat2::*;
+use math_lib::mat3::*;
+
+mod common;
+use common::*;
+
+fn spd() -> Mat3<f64> {
+    mat3(
+        vec3(4.0, 2.0, -2.0),
+        vec3(2.0, 10.0, 2.0),
+        vec3(-2.0, 2.0, 6.0),
+    )
+}
+
+#[test]
+fn solve() {
+    let m = mat3(
+        vec3(0.5, -1.25, 2.0),
+        vec3(1.0, 4.75, -2.5),
+        vec3(0.0, 5.0, 1.5),
+    );
+    let x = vec3(1.5, -2.0, 0.25);
+    let r = m.solve(m.apply_to(x)).unwrap();
+    assert!((r - x).abs().max_elem() < 1e-12);
+}
+
+#[test]
+fn solve_singular() {
+    let m = mat3(
+        vec3(1.0, 2.0, 3.0),
+        vec3(2.0, 4.0, 6.0),
+        vec3(0.0, 1.0, 1.0),
+    );
+    assert_eq!(m.lu().unwrap().rank, 2);
+    assert_eq!(m.solve(vec3(1.0, 2.0, 3.0)), None);
+}
+
+#[test]
+fn solve_int() {
+    //the first multiplier would be 1 / 2, which used to be truncated to 0 and gave (0, 2)
+    assert_eq!(mat2(vec2(2i64, 1), vec2(1, 1)).solve(vec2(3, 2)), Some(vec2(1, 1)));
+    assert_eq!(mat2(vec2(2i64, 1), vec2(1, 1)).solve(vec2(3, 3)), Some(vec2(0, 3)));
+    let m = mat3(vec3(2i64, 3, 1), vec3(3, 1, 2), vec3(1, 2, 3));
+    assert!(m.lu().is_none());
+    assert_eq!(m.solve(m.apply_to(vec3(4, -7, 2))), Some(vec3(4, -7, 2)));
+    //rank deficient, with and without a solution
+    assert_eq!(mat2(vec2(2i64, 1), vec2(4, 2)).solve(vec2(3, 6)), None);
+    assert_eq!(mat3(vec3(2i64, 3, 1), vec3(4, 6, 2), vec3(1, 2, 3)).solve(vec3(1, 1, 1)), None);
+    let mut seed = 7u64;
+    let mut rnd = || rnd_int(&mut seed, 4);
+    for _ in 0..1000 {
+        let m = mat3(vec3(rnd(), rnd(), rnd()), vec3(rnd(), rnd(), rnd()), vec3(rnd(), rnd(), rnd()));
+        let x = vec3(rnd(), rnd(), rnd());
+        assert_eq!(m.solve(m.apply_to(x)), if m.det() == 0 { None } else { Some(x) });
+    }
+    assert_eq!(mat2(vec2(2i64, 1), vec2(-2, 3)).solve(vec2(3, 1)), Some(vec2(1, 1)));
+    //exact multipliers but a fractional solution
+    assert_eq!(mat2(vec2(2i64, 0), vec2(0, 1)).solve(vec2(1, 1)), None);
+}
+
+#[test]
+fn cholesky() {
+    let m = spd();
+    let l = m.cholesky().unwrap();
+    assert_eq!(l.x.y, 0.0);
+    assert!(approx_eq(l * l.transpose(), m));
+    let x = vec3(1.0, -1.0, 2.0);
+    assert!((m.cholesky_solve(m.apply_to(x)).unwrap() - x).abs().max_elem() < 1e-12);
+}
+
+#[test]
+fn cholesky_not_spd() {
+    //symmetric and invertible but indefinite
+    let m = mat3(
+        vec3(1.0, 2.0, 0.0),
+        vec3(2.0, 1.0, 0.0),
+        vec3(0.0, 0.0, 1.0),
+    );
+    assert_eq!(m.cholesky(), None);
+    assert_eq!(m.cholesky_solve(vec3(1.0, 1.0, 1.0)), None);
+    //positive semidefinite isn't enough either
+    assert_eq!(mat2(vec2(1.0, 1.0), vec2(1.0, 1.0)).cholesky(), None);
+}
+
+#[test]
+fn ldlt() {
+    for m in [spd(), mat3(vec3(1.0, 2.0, 0.0), vec3(2.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0))] {
+        let (l, d) = m.ldlt().unwrap();
+        assert_eq!((l.x.x, l.y.y, l.z.z), (1.0, 1.0, 1.0));
+        let dm = mat3(vec3(d.x, 0.0, 0.0), vec3(0.0, d.y, 0.0), vec3(0.0, 0.0, d.z));
+        assert!(approx_eq(l * dm * l.transpose(), m));
+    }
+}
+
+#[test]
+fn ldlt_singular() {
+    assert_eq!(mat2(vec2(1.0, 1.0), vec2(1.0, 1.0)).ldlt(), None);
+}
+
+#[test]
+fn ldlt_int() {
+    assert_eq!(mat2(vec2(2i64, 1), vec2(1, 1)).ldlt(), None);
+    assert_eq!(mat2(vec2(2i64, 4), vec2(4, 11)).ldlt(), Some((mat2(vec2(1, 0), vec2(2, 1)), vec2(2, 3))));
+}
+
+fn diag3(v: Vec3<f64>) -> Mat3<f64> {
+    mat3(vec3(v.x, 0.0, 0.0), vec3(0.0, v.y, 0.0), vec3(0.0, 0.0, v.z))
+}
+
+#[test]
+fn symmetric_eigen() {
+    let m = spd();
+    let (values, vectors) = m.symmetric_eigen();
+    assert!(values.x >= values.y && values.y >= values.z);
+    assert!(approx_eq(vectors.transpose() * vectors, Mat3::ident()));
+    assert!(approx_eq(vectors * diag3(values) * vectors.transpose(), m));
+    let m = mat2(vec2(3.0, 1.0), vec2(1.0, -2.0));
+    let (values, vectors) = m.symmetric_eigen();
+    assert!(values.x >= values.y);
+    for (value, vector) in [(values.x, vec2(vectors.x.x, vectors.y.x)), (values.y, vec2(vectors.x.y, vectors.y.y))] {
+        assert!((m.apply_to(vector) - vector * value).abs().max_elem() < 1e-12);
+        assert!((vector.magnitude() - 1.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn svd_polar() {
+    //det < 0, so the reflection has to end up in a negative singular value
+    let m = mat3(
+        vec3(0.5, -1.25, 2.0),
+        vec3(1.0, 4.75, -2.5),
+        vec3(0.0, 5.0, 1.5),
+    ) * mat3(vec3(-1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0));
+    let (u, s, vt) = m.svd();
+    assert!(approx_eq(u * diag3(s) * vt, m));
+    assert!((u.det() - 1.0).abs() < 1e-9 && (vt.det() - 1.0).abs() < 1e-9);
+    assert!(s.z < 0.0);
+    let (r, p) = m.polar();
+    assert!(approx_eq(r * p, m));
+    assert!(approx_eq(r.transpose() * r, Mat3::ident()));
+    assert!(approx_eq(p, p.transpose()));
+    let m = mat2(vec2(1.0, 2.0), vec2(-0.5, 3.0));
+    let (u, s, vt) = m.svd();
+    let r = u * mat2(vec2(s.x, 0.0), vec2(0.0, s.y)) * vt - m;
+    assert!(r.x.abs().max_elem() < 1e-12 && r.y.abs().max_elem() < 1e-12);
+    let (r, p) = m.polar();
+    let d = r * p - m;
+    assert!(d.x.abs().max_elem() < 1e-12 && d.y.abs().max_elem() < 1e-12);
+    assert!((r.det() - 1.0).abs() < 1e-12 && (p.x.y - p.y.x).abs() < 1e-12);
+}
+
+#[test]
+fn qr() {
+    let m = spd() + mat3(vec3(0.0, 1.0, -3.0), vec3(0.5, 0.0, 0.0), vec3(2.0, 0.0, 0.0));
+    let (q, r) = m.qr();
+    assert!(approx_eq(q * r, m));
+    assert!(approx_eq(q.transpose() * q, Mat3::ident()));
+    assert_eq!((r.y.x, r.z.x, r.z.y), (0.0, 0.0, 0.0));
+}
+
+#[test]
+fn least_squares() {
+    //exact samples of y = 2 + 0.5x + 0.25x^2 are fit exactly, with noise the residual is orthogonal to every column
+    let xs = [-2.0, -1.0, 0.0, 0.5, 1.0, 3.0];
+    let rows: Vec<_> = xs.iter().map(|&x| vec3(1.0, x, x * x)).collect();
+    let exact: Vec<_> = xs.iter().map(|&x| 2.0 + 0.5 * x + 0.25 * x * x).collect();
+    let fit = Mat3::least_squares(&rows, &exact).unwrap();
+    assert!((fit - vec3(2.0, 0.5, 0.25)).abs().max_elem() < 1e-12);
+    let noisy: Vec<_> = exact.iter().zip(&[0.1, -0.2, 0.05, 0.3, -0.1, 0.02]).map(|(y, n)| y + n).collect();
+    let fit = Mat3::least_squares(&rows, &noisy).unwrap();
+    let mut normal = vec3(0.0, 0.0, 0.0);
+    for (row, y) in rows.iter().zip(&noisy) {
+        normal += *row * (dot(*row, fit) - y);
+    }
+    assert!(normal.abs().max_elem() < 1e-12);
+}
+
+#[test]
+fn least_squares_degenerate() {
+    //every row is a multiple of (1, 2), so the second direction is never constrained
+    let rows = [vec2(1.0, 2.0), vec2(2.0, 4.0), vec2(-1.0, -2.0)];
+    assert_eq!(Mat2::least_squares(&rows, &[1.0, 2.0, -1.0]), None);
+    assert_eq!(Mat2::<f64>::least_squares(&[], &[]), None);
+    assert_eq!(Mat2::least_squares(&rows, &[1.0, 2.0]), None);
+}
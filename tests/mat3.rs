@@ -0,0 +1,32 @@
+use math_lib::vec2::*;
+use math_lib::mat2::*;
+use math_lib::mat3::*;
+
+mod common;
+use common::*;
+
+#[test]
+fn transform_2d_int() {
+    let m = Mat3::from_scale_2d(vec2(2, 3)).then_shear_2d(vec2(1, 0)).then_translate_2d(vec2(-1, 4));
+    //(1, 1) scaled to (2, 3), sheared to (5, 3), translated to (4, 7)
+    assert_eq!(m.transform_point2(vec2(1, 1)), vec2(4, 7));
+    assert_eq!(m.transform_vector2(vec2(1, 1)), vec2(5, 3));
+    assert_eq!(Mat3::from_translation_2d(vec2(5, 6)).transform_vector2(vec2(1, 2)), vec2(1, 2));
+    assert_eq!(Mat3::from_shear_2d(vec2(0, 2)).transform_point2(vec2(3, 1)), vec2(3, 7));
+    let l = mat2(vec2(1, 2), vec2(3, 4));
+    assert_eq!(Mat3::from_mat2_2d(l, vec2(1, 1)).transform_point2(vec2(1, -1)), l.apply_to(vec2(1, -1)) + vec2(1, 1));
+}
+
+#[test]
+fn transform_2d_float() {
+    let quarter = std::f64::consts::FRAC_PI_2;
+    assert!(approx_eq(Mat3::from_angle_2d(quarter).transform_point2(vec2(1.0, 0.0)), vec2(0.0, 1.0)));
+    let (t, angle, s) = (vec2(3.0, -2.0), 0.7, vec2(2.0, 0.5));
+    let trs = Mat3::from_trs_2d(t, angle, s);
+    let chained = Mat3::from_scale_2d(s).then_rotate_2d(angle).then_translate_2d(t);
+    for &p in &[vec2(1.0, 0.0), vec2(0.0, 1.0), vec2(-2.5, 4.0)] {
+        assert!(approx_eq(trs.transform_point2(p), chained.transform_point2(p)));
+        assert!(approx_eq(trs.transform_point2(p), Mat2::rotate(angle).apply_to(p * s) + t));
+        assert!(approx_eq(trs.transform_vector2(p), Mat2::rotate(angle).apply_to(p * s)));
+    }
+}
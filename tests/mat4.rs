@@ -0,0 +1,326 @@
+use std::ops::{Add,Sub,Mul,Div,Neg};
+use math_lib::vec3::*;
+use math_lib::vec4::*;
+use math_lib::mat3::*;
+use math_lib::mat4::*;
+use math_lib::quaternion::*;
+
+mod common;
+use common::*;
+
+fn scaled_ident<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T> + Div<Output=T> + Sub<Output=T> + Neg<Output=T> + Abs>(s: T) -> Mat4<T> {
+    let Mat4{ x,y,z,w } = Mat4::ident();
+    mat4(x * s, y * s, z * s, w * s)
+}
+
+#[test]
+fn adjoint_int() {
+    let m = mat4(
+        vec4(2, -1, 0, 3),
+        vec4(1, 4, -2, 0),
+        vec4(0, 5, 1, -1),
+        vec4(-3, 2, 2, 1),
+    );
+    assert_eq!(m * m.adjoint(), scaled_ident(m.det()));
+    assert_eq!(m.adjoint() * m, scaled_ident(m.det()));
+    assert_eq!(Mat4::<i64>::ident().adjoint(), Mat4::ident());
+}
+
+#[test]
+fn adjoint_singular_int() {
+    let m = mat4(
+        vec4(1, 2, 3, 4),
+        vec4(2, 4, 6, 8),
+        vec4(0, 1, 0, 1),
+        vec4(5, 0, 2, 7),
+    );
+    assert_eq!(m.det(), 0);
+    assert_eq!(m * m.adjoint(), scaled_ident(0));
+}
+
+#[test]
+fn adjoint_float() {
+    let m = mat4(
+        vec4(0.5, -1.25, 2.0, 3.5),
+        vec4(1.0, 4.75, -2.5, 0.125),
+        vec4(0.0, 5.0, 1.5, -1.0),
+        vec4(-3.25, 2.0, 2.0, 1.0),
+    );
+    assert!(approx_eq(m * m.adjoint(), scaled_ident(m.det())));
+    assert!(approx_eq(m * m.inv(), Mat4::ident()));
+}
+
+//the inverse as it was computed before adjoint_det: the 24 term determinant and 3x3 minors for the cofactors
+fn old_inv(m: Mat4<f64>) -> Mat4<f64> {
+    let Mat4{ x,y,z,w } = m;
+    let det =
+          x.x * y.y * z.z * w.w
+        + x.x * y.z * z.w * w.y
+        + x.x * y.w * z.y * w.z
+        + x.y * y.x * z.w * w.z
+        + x.y * y.z * z.x * w.w
+        + x.y * y.w * z.z * w.x
+        + x.z * y.x * z.y * w.w
+        + x.z * y.y * z.w * w.x
+        + x.z * y.w * z.x * w.y
+        + x.w * y.x * z.z * w.y
+        + x.w * y.y * z.x * w.z
+        + x.w * y.z * z.y * w.x
+        - x.x * y.y * z.w * w.z
+        - x.x * y.z * z.y * w.w
+        - x.x * y.w * z.z * w.y
+        - x.y * y.x * z.z * w.w
+        - x.y * y.z * z.w * w.x
+        - x.y * y.w * z.x * w.z
+        - x.z * y.x * z.w * w.y
+        - x.z * y.y * z.x * w.w
+        - x.z * y.w * z.y * w.x
+        - x.w * y.x * z.y * w.z
+        - x.w * y.y * z.z * w.x
+        - x.w * y.z * z.x * w.y;
+    let rows: [[f64; 4]; 4] = m.into();
+    let minor = |i: usize, j: usize| {
+        let r: Vec<Vec3<f64>> = (0..4).filter(|&r| r != i).map(|r| {
+            let c: Vec<f64> = (0..4).filter(|&c| c != j).map(|c| rows[r][c]).collect();
+            vec3(c[0], c[1], c[2])
+        }).collect();
+        mat3(r[0], r[1], r[2]).det()
+    };
+    //the adjoint is the transposed cofactor matrix
+    let mut inv = [[0.0; 4]; 4];
+    for (i, row) in inv.iter_mut().enumerate() {
+        for (j, x) in row.iter_mut().enumerate() {
+            let sign = if (i + j) % 2 == 0 { 1.0 } else { -1.0 };
+            *x = sign * minor(j, i) / det;
+        }
+    }
+    inv.into()
+}
+
+#[test]
+fn inv_matches_old_inv() {
+    let mut seed = 6;
+    for _ in 0..10000 {
+        //the identity plus up to 0.25 in every entry keeps the condition number small
+        let mut r = || rnd(&mut seed) * 0.25;
+        let m = Mat4::ident() + mat4(vec4(r(), r(), r(), r()), vec4(r(), r(), r(), r()), vec4(r(), r(), r(), r()), vec4(r(), r(), r(), r()));
+        let d: [[f64; 4]; 4] = (m.inv() - old_inv(m)).into();
+        let r: [[f64; 4]; 4] = old_inv(m).into();
+        let max = |a: &[[f64; 4]; 4]| a.iter().flatten().fold(0.0f64, |a, &b| a.max(b.abs()));
+        //relative to the largest entry, the worst seen is about 7 ulps
+        assert!(max(&d) <= 16.0 * f64::EPSILON * max(&r), "{:?}", m);
+    }
+}
+
+#[test]
+fn inv_affine() {
+    let m = mat4(
+        vec4(0.0, -1.0, 0.0, 3.0),
+        vec4(2.0, 0.0, 0.0, -1.0),
+        vec4(0.0, 0.0, 0.5, 2.0),
+        vec4(0.0, 0.0, 0.0, 1.0),
+    );
+    assert!(approx_eq(m.inv_affine(), m.inv()));
+    assert!(approx_eq(m * m.inv_affine(), Mat4::ident()));
+}
+
+//clip space after the perspective divide
+fn ndc(m: Mat4<f64>, p: Vec3<f64>) -> Vec3<f64> {
+    let c = m.apply_to(vec4(p.x, p.y, p.z, 1.0));
+    vec3(c.x, c.y, c.z) / c.w
+}
+
+#[test]
+fn look_at() {
+    let (eye, target, up) = (vec3(1.0, 2.0, 3.0), vec3(4.0, -2.0, 3.0), vec3(0.0, 0.0, 1.0));
+    let rh = Mat4::look_at_rh(eye, target, up);
+    let lh = Mat4::look_at_lh(eye, target, up);
+    assert!(approx_eq(ndc(rh, eye), Vec3::zero()));
+    assert!(approx_eq(ndc(rh, target), vec3(0.0, 0.0, -5.0)));
+    assert!(approx_eq(ndc(rh, eye + up), vec3(0.0, 1.0, 0.0)));
+    assert!(approx_eq(ndc(lh, target), vec3(0.0, 0.0, 5.0)));
+    assert!(approx_eq(ndc(lh, eye + up), vec3(0.0, 1.0, 0.0)));
+    //the two only differ in which way x and z point
+    assert!(approx_eq(ndc(rh, eye + cross(target - eye, up)), -ndc(lh, eye + cross(target - eye, up))));
+}
+
+#[test]
+fn perspective() {
+    let (fov_y, aspect, near, far) = (1.2f64, 1.5, 0.5, 40.0);
+    let (tan, top) = ((fov_y / 2.0).tan(), (fov_y / 2.0).tan() * near);
+    for &(depth, low) in &[(ClipDepth::NegOneToOne, -1.0), (ClipDepth::ZeroToOne, 0.0)] {
+        let rh = Mat4::perspective_rh(fov_y, aspect, near, far, depth);
+        let lh = Mat4::perspective_lh(fov_y, aspect, near, far, depth);
+        //the top right corner of the near plane and the middle of the far plane
+        assert!(approx_eq(ndc(rh, vec3(top * aspect, top, -near)), vec3(1.0, 1.0, low)));
+        assert!(approx_eq(ndc(rh, vec3(0.0, 0.0, -far)), vec3(0.0, 0.0, 1.0)));
+        assert!(approx_eq(ndc(lh, vec3(top * aspect, top, near)), vec3(1.0, 1.0, low)));
+        assert!(approx_eq(ndc(lh, vec3(0.0, 0.0, far)), vec3(0.0, 0.0, 1.0)));
+        //edges of the field of view stay on the edges at any depth
+        assert!(approx_eq(ndc(rh, vec3(-10.0 * tan * aspect, -10.0 * tan, -10.0)).xy().extend(0.0), vec3(-1.0, -1.0, 0.0)));
+        let rh = Mat4::perspective_infinite_rh(fov_y, aspect, near, depth);
+        let lh = Mat4::perspective_infinite_lh(fov_y, aspect, near, depth);
+        assert!(approx_eq(ndc(rh, vec3(0.0, 0.0, -near)), vec3(0.0, 0.0, low)));
+        assert!(approx_eq(ndc(lh, vec3(0.0, 0.0, near)), vec3(0.0, 0.0, low)));
+        assert!((ndc(rh, vec3(0.0, 0.0, -1e12)).z - 1.0).abs() < 1e-9);
+        assert!((ndc(lh, vec3(0.0, 0.0, 1e12)).z - 1.0).abs() < 1e-9);
+    }
+    let rh = Mat4::perspective_reversed_rh(fov_y, aspect, near, far);
+    let lh = Mat4::perspective_reversed_lh(fov_y, aspect, near, far);
+    assert!(approx_eq(ndc(rh, vec3(0.0, 0.0, -near)), vec3(0.0, 0.0, 1.0)));
+    assert!(approx_eq(ndc(rh, vec3(0.0, 0.0, -far)), vec3(0.0, 0.0, 0.0)));
+    assert!(approx_eq(ndc(lh, vec3(0.0, 0.0, near)), vec3(0.0, 0.0, 1.0)));
+    assert!(approx_eq(ndc(lh, vec3(0.0, 0.0, far)), vec3(0.0, 0.0, 0.0)));
+    let rh = Mat4::perspective_infinite_reversed_rh(fov_y, aspect, near);
+    let lh = Mat4::perspective_infinite_reversed_lh(fov_y, aspect, near);
+    assert!(approx_eq(ndc(rh, vec3(0.0, 0.0, -near)), vec3(0.0, 0.0, 1.0)));
+    assert!(approx_eq(ndc(lh, vec3(0.0, 0.0, near)), vec3(0.0, 0.0, 1.0)));
+    assert!(ndc(rh, vec3(0.0, 0.0, -1e12)).z.abs() < 1e-9);
+}
+
+#[test]
+fn orthographic() {
+    let (left, right, bottom, top, near, far) = (-2.0, 6.0, -1.0, 3.0, 0.5, 10.0);
+    for &(depth, low) in &[(ClipDepth::NegOneToOne, -1.0), (ClipDepth::ZeroToOne, 0.0)] {
+        let rh = Mat4::orthographic_rh(left, right, bottom, top, near, far, depth);
+        let lh = Mat4::orthographic_lh(left, right, bottom, top, near, far, depth);
+        assert!(approx_eq(ndc(rh, vec3(left, bottom, -near)), vec3(-1.0, -1.0, low)));
+        assert!(approx_eq(ndc(rh, vec3(right, top, -far)), vec3(1.0, 1.0, 1.0)));
+        assert!(approx_eq(ndc(lh, vec3(left, bottom, near)), vec3(-1.0, -1.0, low)));
+        assert!(approx_eq(ndc(lh, vec3(right, top, far)), vec3(1.0, 1.0, 1.0)));
+        //no perspective divide
+        assert_eq!(rh.w, vec4(0.0, 0.0, 0.0, 1.0));
+    }
+}
+
+#[test]
+fn decompose_compose() {
+    let mut seed = 1;
+    for _ in 0..1000 {
+        let mut d = Decomposition {
+            scale: rnd_vec3(&mut seed).map(|x| 0.5 + x.abs() * 2.0),
+            shear: rnd_vec3(&mut seed) * 0.5,
+            rotation: rnd_quaternion(&mut seed).normalize(),
+            translation: rnd_vec3(&mut seed) * 10.0,
+            perspective: vec4(0.0, 0.0, 0.0, 1.0),
+        };
+        let m = d.compose();
+        let e = m.decompose().unwrap();
+        assert!(approx_eq(e.compose(), m));
+        assert!(approx_eq(e.scale, d.scale) && approx_eq(e.shear, d.shear) && approx_eq(e.translation, d.translation));
+        assert_eq!(e.perspective, d.perspective);
+        //q and -q are the same rotation
+        assert!((e.rotation.dot(d.rotation).abs() - 1.0).abs() < 1e-9);
+        //with perspective w.w generally isn't 1, so only the transform comes back
+        d.perspective = vec4(rnd(&mut seed) * 0.02, rnd(&mut seed) * 0.02, rnd(&mut seed) * 0.02, 2.0);
+        let m = d.compose();
+        let p = rnd_vec3(&mut seed);
+        for &m in &[m, scaled_ident(3.0).mul(m)] {
+            let e = m.decompose().unwrap();
+            assert!(approx_eq(e.compose().transform_point3(p), m.transform_point3(p)));
+            assert!(approx_eq(e.compose(), mat4(m.x / m.w.w, m.y / m.w.w, m.z / m.w.w, m.w / m.w.w)));
+        }
+    }
+}
+
+#[test]
+fn decompose_reflection() {
+    let mut seed = 2;
+    for _ in 0..1000 {
+        let rotation = rnd_quaternion(&mut seed).normalize();
+        let scale = vec3(rnd(&mut seed), rnd(&mut seed), rnd(&mut seed)).map(|x| 0.5 + x.abs());
+        //mirrored in y
+        let m = Mat4::from_scale_rotation_translation(scale * vec3(1.0, -1.0, 1.0), rotation, rnd_vec3(&mut seed));
+        assert!(m.det() < 0.0);
+        let d = m.decompose().unwrap();
+        //the mirror always ends up in x, with a proper rotation
+        assert!(d.scale.x < 0.0 && d.scale.y > 0.0 && d.scale.z > 0.0);
+        assert!((d.rotation.magnitude() - 1.0).abs() < 1e-9);
+        assert!(approx_eq(d.compose(), m));
+        let (s, r, t) = m.to_scale_rotation_translation();
+        assert!(approx_eq(Mat4::from_scale_rotation_translation(s, r, t), m));
+    }
+}
+
+#[test]
+fn decompose_singular() {
+    //the third column is the sum of the others, which only cancels to zero up to rounding
+    let m = mat4(
+        vec4(0.1, 0.7, 0.1 + 0.7, 1.0),
+        vec4(0.3, 0.2, 0.3 + 0.2, 2.0),
+        vec4(0.6, 0.9, 0.6 + 0.9, 3.0),
+        vec4(0.0, 0.0, 0.0, 1.0),
+    );
+    assert!(m.decompose().is_none());
+    let big = mat4(m.x * 1e6, m.y * 1e6, m.z * 1e6, m.w);
+    assert!(big.decompose().is_none());
+    assert!(Mat4::from_scale(vec3(1.0, 0.0, 1.0)).decompose().is_none());
+    let mut m = Mat4::<f64>::ident();
+    m.w.w = 0.0;
+    assert!(m.decompose().is_none());
+    //tiny but not singular
+    assert!(Mat4::from_scale(vec3(1e-6, 1e-6, 1e-6)).decompose().is_some());
+}
+
+#[test]
+fn transform_point3() {
+    let proj = Mat4::perspective_rh(1.0, 1.5, 0.5, 40.0, ClipDepth::ZeroToOne);
+    let affine = Mat4::from_trs(vec3(1.0, -2.0, 3.0), quaternion(0.3, -0.5, 0.2, 0.7).normalize(), vec3(2.0, 0.5, 1.0));
+    let mut seed = 3;
+    for _ in 0..1000 {
+        let p = rnd_vec3(&mut seed) * 10.0;
+        //w is divided out for projections and is 1 for affine transforms
+        assert!(approx_eq(proj.transform_point3(p), ndc(proj, p)));
+        assert!(approx_eq(affine.transform_point3(p), ndc(affine, p)));
+        assert!(approx_eq(affine.transform_vector3(p), affine.transform_point3(p) - affine.transform_point3(Vec3::zero())));
+        assert_eq!(affine.project_point3(p), Some(affine.transform_point3(p)));
+        //w is -z, so points behind the camera aren't projected
+        assert_eq!(proj.project_point3(p).is_some(), p.z < 0.0);
+    }
+    assert_eq!(proj.project_point3(vec3(1.0, 1.0, 0.0)), None);
+}
+
+#[test]
+fn transform_slices() {
+    let proj = Mat4::perspective_lh(1.0, 1.5, 0.5, 40.0, ClipDepth::NegOneToOne);
+    let mut seed = 4;
+    let input: Vec<_> = (0..100).map(|_| rnd_vec3(&mut seed)).collect();
+    let mut output = vec![Vec3::zero(); 100];
+    let mut projected = vec![None; 100];
+    proj.transform_point3_slice(&input, &mut output);
+    assert!(input.iter().zip(&output).all(|(&p, &o)| o == proj.transform_point3(p)));
+    proj.transform_vector3_slice(&input, &mut output);
+    assert!(input.iter().zip(&output).all(|(&v, &o)| o == proj.transform_vector3(v)));
+    proj.project_point3_slice(&input, &mut projected);
+    assert!(input.iter().zip(&projected).all(|(&p, &o)| o == proj.project_point3(p)));
+    proj.transform_normal_slice(&input, &mut output);
+    assert!(input.iter().zip(&output).all(|(&n, &o)| o == proj.transform_normal(n)));
+}
+
+#[test]
+#[should_panic]
+fn transform_slice_lengths() {
+    Mat4::<f64>::ident().transform_point3_slice(&[Vec3::zero(); 3], &mut [Vec3::zero(); 2]);
+}
+
+#[test]
+fn normal_matrix() {
+    let m = Mat4::from_trs(vec3(1.0, -2.0, 3.0), quaternion(0.3, -0.5, 0.2, 0.7).normalize(), vec3(4.0, 0.25, 1.0));
+    let mut seed = 5;
+    for _ in 0..1000 {
+        //a plane through the origin spanned by two tangents, with n perpendicular to both
+        let (a, b) = (rnd_vec3(&mut seed), rnd_vec3(&mut seed));
+        let n = cross(a, b);
+        let (ta, tb) = (m.transform_vector3(a), m.transform_vector3(b));
+        let tn = m.transform_normal(n);
+        assert!(dot(tn, ta).abs() < 1e-9 && dot(tn, tb).abs() < 1e-9);
+        assert!((tn.magnitude() - 1.0).abs() < 1e-9);
+        //same side as the transformed plane's own normal, as m has a positive determinant
+        assert!(dot(tn, cross(ta, tb)) > 0.0);
+    }
+    //transforming a normal like any other vector doesn't keep it perpendicular under non-uniform scale
+    let s = Mat4::from_scale(vec3(4.0, 0.25, 1.0));
+    let (n, t) = (vec3(1.0, 1.0, 0.0), vec3(1.0, -1.0, 0.0));
+    assert!(dot(s.transform_vector3(n), s.transform_vector3(t)) > 1.0);
+    assert_eq!(dot(s.transform_normal(n), s.transform_vector3(t)), 0.0);
+}
@@ -0,0 +1,263 @@
+use math_lib::quaternion::*;
+use math_lib::vec3::*;
+
+mod common;
+use common::*;
+
+#[test]
+fn inv() {
+    let mut seed = 1;
+    for _ in 0..1000 {
+        //spread the magnitudes over a few orders so non-unit input is covered
+        let s = 10.0f64.powf(rnd(&mut seed) * 3.0);
+        let Quaternion{ r,i,j,k } = rnd_quaternion(&mut seed);
+        let q = quaternion(r * s, i * s, j * s, k * s);
+        assert!(approx_eq(q * q.inv(), Quaternion::ident()));
+        assert!(approx_eq(q.inv() * q, Quaternion::ident()));
+    }
+}
+
+#[test]
+fn inv_unit() {
+    let mut seed = 2;
+    for _ in 0..1000 {
+        let q = rnd_quaternion(&mut seed).normalize();
+        assert!(approx_eq(q * q.inv_unit(), Quaternion::ident()));
+        assert!(approx_eq(q.inv_unit(), q.inv()));
+    }
+}
+
+#[test]
+fn conjugate() {
+    let q = quaternion(1, 2, -3, 4);
+    assert_eq!(q.conjugate(), quaternion(1, -2, 3, -4));
+    assert_eq!(q.norm_squared(), 30);
+    assert_eq!(q.dot(quaternion(2, 0, 1, 1)), 3);
+    assert_eq!(q * q.conjugate(), quaternion(30, 0, 0, 0));
+}
+
+#[test]
+fn div() {
+    let mut seed = 3;
+    for _ in 0..1000 {
+        let (a, b) = (rnd_quaternion(&mut seed), rnd_quaternion(&mut seed));
+        assert!(approx_eq(a / b * b, a));
+        assert!(approx_eq(a / b, a * b.inv()));
+    }
+}
+
+#[test]
+fn div_int() {
+    let (a, b) = (quaternion(1i64, 2, 3, 4), quaternion(2i64, -1, 0, 3));
+    assert_eq!(a / a, Quaternion::ident());
+    assert_eq!((a * b) / b, a);
+    assert_eq!((a * b).exact_div(b), Some(a));
+    let mut q = a * b;
+    q /= b;
+    assert_eq!(q, a);
+    //not a multiple of b, so the components truncate like integer division
+    assert_eq!(a.exact_div(b), None);
+    assert_eq!(quaternion(3i64, 0, 0, 0) / quaternion(2, 0, 0, 0), Quaternion::ident());
+}
+
+#[test]
+fn assign_ops() {
+    let mut seed = 10;
+    for _ in 0..100 {
+        let (a, b) = (rnd_quaternion(&mut seed), rnd_quaternion(&mut seed));
+        let s = rnd(&mut seed) + 2.0;
+        let mut q = a;
+        q *= b;
+        assert_eq!(q, a * b);
+        q /= b;
+        assert!(approx_eq(q, a));
+        q = a;
+        q += b;
+        q -= a;
+        assert!(approx_eq(q, b));
+        q *= s;
+        assert_eq!(q, b * s);
+        q /= s;
+        assert!(approx_eq(q, b));
+        assert!(approx_eq(-a + a, Quaternion::zero()));
+    }
+    let q = quaternion(1, 2, 3, 4);
+    assert_eq!(vec![q, q, q].into_iter().sum::<Quaternion<i32>>(), q * 3);
+    assert_eq!(vec![q, q].into_iter().product::<Quaternion<i32>>(), q * q);
+    assert_eq!(Quaternion::<i32>::one(), Quaternion::ident());
+}
+
+#[test]
+fn rotate() {
+    let mut seed = 4;
+    for _ in 0..1000 {
+        let q = rnd_quaternion(&mut seed).normalize();
+        let v = vec3(rnd(&mut seed), rnd(&mut seed), rnd(&mut seed));
+        let r = q.rotate(v);
+        let m = q.rot_mat().apply_to(v);
+        let p = q * quaternion(0.0, v.x, v.y, v.z) * q.conjugate();
+        assert!((r - m).abs().max_elem() < 1e-12);
+        assert!((r - vec3(p.i, p.j, p.k)).abs().max_elem() < 1e-12);
+        assert!((q * v - r).abs().max_elem() < 1e-12);
+    }
+    let q = quaternion(0.5f64, 0.5, 0.5, 0.5);
+    let input = [vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0)];
+    let mut output = [vec3(0.0, 0.0, 0.0); 3];
+    q.rotate_slice(&input, &mut output);
+    assert_eq!(output, [vec3(0.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0), vec3(1.0, 0.0, 0.0)]);
+}
+
+#[test]
+fn from_rot_mat() {
+    let mut seed = 5;
+    //half turns about random axes are where the trace based formula breaks down, so cover them too
+    let half_turns = (0..100).map(|_| {
+        let axis = vec3(rnd(&mut seed), rnd(&mut seed), rnd(&mut seed)).normalize();
+        quaternion(0.0, axis.x, axis.y, axis.z)
+    }).collect::<Vec<_>>();
+    for q in (0..1000).map(|_| rnd_quaternion(&mut seed).normalize()).chain(half_turns) {
+        let r = Quaternion::from_rot_mat(q.rot_mat());
+        assert!(approx_eq(r, q) || approx_eq(r, -q), "{:?} {:?}", q, r);
+        assert_eq!(Quaternion::from(q.rot_mat()), r);
+    }
+}
+
+//the rotation angle between two unit quaternions
+fn angle(a: Quaternion<f64>, b: Quaternion<f64>) -> f64 {
+    2.0 * a.dot(b).abs().min(1.0).acos()
+}
+
+#[test]
+fn slerp() {
+    let mut seed = 6;
+    for _ in 0..1000 {
+        let (a, b) = (rnd_quaternion(&mut seed).normalize(), rnd_quaternion(&mut seed).normalize());
+        let t = (rnd(&mut seed) + 1.0) / 2.0;
+        let q = a.slerp(b, t);
+        assert!((q.magnitude() - 1.0).abs() < 1e-12);
+        //constant speed along the shorter arc, b and -b are the same rotation
+        assert!((angle(a, q) - t * angle(a, b)).abs() < 1e-9);
+        assert!((angle(q, b) - (1.0 - t) * angle(a, b)).abs() < 1e-9);
+        assert!(approx_eq(a.slerp(-b, t), q) || approx_eq(a.slerp(-b, t), -q));
+        let n = a.nlerp(b, t);
+        assert!((n.magnitude() - 1.0).abs() < 1e-12 && angle(n, q) < 0.3);
+    }
+    let (a, b) = (quaternion(1.0, 0.0, 0.0, 0.0), quaternion(0.6, 0.8, 0.0, 0.0));
+    assert!(approx_eq(a.slerp(b, 0.0), a) && approx_eq(a.slerp(b, 1.0), b));
+    //nearly identical input takes the nlerp path
+    let c = quaternion(1.0, 1e-10, 0.0, 0.0).normalize();
+    assert!(approx_eq(a.slerp(c, 0.5), quaternion(1.0, 5e-11, 0.0, 0.0)));
+}
+
+#[test]
+fn squad() {
+    let mut seed = 7;
+    let keys: Vec<_> = (0..5).map(|_| rnd_quaternion(&mut seed).normalize()).collect();
+    let control: Vec<_> = (0..keys.len()).map(|n| {
+        Quaternion::squad_control(keys[n.saturating_sub(1)], keys[n], keys[(n + 1).min(keys.len() - 1)])
+    }).collect();
+    let eval = |n: usize, t: f64| keys[n].squad(control[n], control[n + 1], keys[n + 1], t);
+    for n in 0..keys.len() - 1 {
+        assert!(approx_eq(eval(n, 0.0), keys[n]));
+        assert!(approx_eq(eval(n, 1.0), keys[n + 1]) || approx_eq(eval(n, 1.0), -keys[n + 1]));
+    }
+    //the angular velocity is continuous across the inner keys
+    let h = 1e-6;
+    for n in 1..keys.len() - 1 {
+        let before = (eval(n - 1, 1.0).conjugate() * eval(n - 1, 1.0 - h)).to_rotation_vector();
+        let after = (eval(n, 0.0).conjugate() * eval(n, h)).to_rotation_vector();
+        assert!((before + after).abs().max_elem() < 1e-6 * h.sqrt(), "{:?} {:?}", before, after);
+    }
+}
+
+#[test]
+fn exp_ln() {
+    let mut seed = 8;
+    for _ in 0..1000 {
+        let q = rnd_quaternion(&mut seed) * 3.0;
+        assert!(approx_eq(q.ln().exp(), q));
+        let u = q.normalize();
+        assert!(approx_eq(u.powf(0.5) * u.powf(0.5), u));
+        assert!(approx_eq(u.powf(1.0), u) && approx_eq(u.powf(-1.0), u.inv()));
+        assert!(approx_eq(u.powf(2.0), u * u));
+    }
+    //real input has no direction for the vector part
+    assert!(approx_eq(quaternion(2.0, 0.0, 0.0, 0.0).ln(), quaternion(2.0f64.ln(), 0.0, 0.0, 0.0)));
+    assert!(approx_eq(quaternion(0.0, 0.0, 0.0, 0.0).exp(), Quaternion::ident()));
+}
+
+#[test]
+fn axis_angle() {
+    let mut seed = 9;
+    for _ in 0..1000 {
+        //up to 1.8 * sqrt(3) < pi, past that to_rotation_vector turns the other way around
+        let v = vec3(rnd(&mut seed), rnd(&mut seed), rnd(&mut seed)) * 1.8;
+        let q = Quaternion::from_rotation_vector(v);
+        assert!((q.to_rotation_vector() - v).abs().max_elem() < 1e-9);
+        let (axis, angle) = q.to_axis_angle();
+        assert!(approx_eq(Quaternion::from_axis_angle(axis * 2.0, angle), q));
+        assert!((q.angle_between(Quaternion::ident()) - v.magnitude()).abs() < 1e-9);
+        let p = rnd_quaternion(&mut seed).normalize();
+        assert!((p.angle_between(p * q) - v.magnitude()).abs() < 1e-9);
+    }
+    assert_eq!(Quaternion::from_rotation_vector(vec3(0.0, 0.0, 0.0)), Quaternion::ident());
+}
+
+#[test]
+fn from_rotation_arc() {
+    let mut seed = 11;
+    for _ in 0..1000 {
+        let a = vec3(rnd(&mut seed), rnd(&mut seed), rnd(&mut seed));
+        let b = vec3(rnd(&mut seed), rnd(&mut seed), rnd(&mut seed));
+        let q = Quaternion::from_rotation_arc(a, b);
+        assert!((q.rotate(a.normalize()) - b.normalize()).abs().max_elem() < 1e-12);
+        //the shortest arc turns about an axis perpendicular to both
+        assert!(dot(vec3(q.i, q.j, q.k), a).abs() < 1e-12 && dot(vec3(q.i, q.j, q.k), b).abs() < 1e-12);
+    }
+    //exactly and nearly opposite in f32, where cancellation used to cost about half the digits
+    let a = vec3(0.2f32, -0.3, 0.9).normalize();
+    assert!((Quaternion::from_rotation_arc(a, -a).rotate(a) + a).abs().max_elem() < 1e-6);
+    let mut e = 1e-5f32;
+    while e < 2e-3 {
+        for b in [vec3(-1.0, e, 0.0), -a + vec3(e, e, 0.0)] {
+            let from = if b.x == -1.0 { vec3(1.0, 0.0, 0.0) } else { a };
+            let q = Quaternion::from_rotation_arc(from, b);
+            assert!((q.rotate(from) - b.normalize()).abs().max_elem() < 1e-6, "{} {:?}", e, q);
+        }
+        e *= 1.05;
+    }
+}
+
+#[test]
+fn look_rotation() {
+    let mut seed = 12;
+    for _ in 0..1000 {
+        let forward = vec3(rnd(&mut seed), rnd(&mut seed), rnd(&mut seed));
+        let up = vec3(rnd(&mut seed), rnd(&mut seed), rnd(&mut seed));
+        let q = Quaternion::look_rotation(forward, up);
+        assert!((q.rotate(vec3(0.0, 0.0, 1.0)) - forward.normalize()).abs().max_elem() < 1e-12);
+        //+y ends up in the plane of forward and up, on the same side as up
+        let y = q.rotate(vec3(0.0, 1.0, 0.0));
+        assert!(dot(y, cross(forward, up)).abs() < 1e-12 && dot(y, up) > 0.0);
+    }
+    //parallel up falls back to the shortest arc
+    let q = Quaternion::look_rotation(vec3(0.0, 2.0, 0.0), vec3(0.0, 1.0, 0.0));
+    assert!((q.rotate(vec3(0.0, 0.0, 1.0)) - vec3(0.0, 1.0, 0.0)).abs().max_elem() < 1e-12);
+}
+
+#[test]
+fn swing_twist() {
+    let mut seed = 13;
+    for _ in 0..1000 {
+        let q = rnd_quaternion(&mut seed).normalize();
+        let axis = vec3(rnd(&mut seed), rnd(&mut seed), rnd(&mut seed));
+        let (swing, twist) = q.swing_twist(axis);
+        assert!(approx_eq(swing * twist, q));
+        //twist turns about axis and swing about something perpendicular to it
+        assert!(cross(vec3(twist.i, twist.j, twist.k), axis).abs().max_elem() < 1e-12);
+        assert!(dot(vec3(swing.i, swing.j, swing.k), axis).abs() < 1e-12);
+    }
+    //a half turn perpendicular to the axis has no twist
+    let q = quaternion(0.0, 1.0, 0.0, 0.0);
+    assert_eq!(q.swing_twist(vec3(0.0, 0.0, 1.0)), (q, Quaternion::ident()));
+}
@@ -0,0 +1,27 @@
+use math_lib::vec2::*;
+use math_lib::vec3::*;
+use math_lib::vec4::*;
+
+#[test]
+fn get() {
+    let v = vec4(1, 2, 3, 4);
+    assert_eq!(v.zyx(), vec3(3, 2, 1));
+    assert_eq!(v.wwxy(), vec4(4, 4, 1, 2));
+    assert_eq!(v.xy(), vec2(1, 2));
+    assert_eq!(vec2(1, 2).xxyy(), vec4(1, 1, 2, 2));
+    assert_eq!(vec2(1, 2).yx(), vec2(2, 1));
+    assert_eq!(vec3(1, 2, 3).zxz(), vec3(3, 1, 3));
+}
+
+#[test]
+fn set() {
+    //components of the argument go to the named fields in order, the rest are kept
+    assert_eq!(vec3(1, 2, 3).with_yz(vec2(5, 6)), vec3(1, 5, 6));
+    assert_eq!(vec3(1, 2, 3).with_zy(vec2(5, 6)), vec3(1, 6, 5));
+    assert_eq!(vec3(1, 2, 3).with_x(7), vec3(7, 2, 3));
+    assert_eq!(vec4(1, 2, 3, 4).with_wx(vec2(5, 6)), vec4(6, 2, 3, 5));
+    assert_eq!(vec4(1, 2, 3, 4).with_wzyx(vec4(1, 2, 3, 4)), vec4(4, 3, 2, 1));
+    //every field is set, so this is just a reorder
+    assert_eq!(vec2(1, 2).with_yx(vec2(5, 6)), vec2(6, 5));
+    assert_eq!(vec2(1, 2).with_xy(vec2(5, 6)), vec2(5, 6));
+}
@@ -0,0 +1,48 @@
+use math_lib::vec3::*;
+use math_lib::vecn::*;
+use math_lib::matn::*;
+use math_lib::mat3::*;
+
+#[test]
+fn ops() {
+    let a = vecn([1, 2, 3, 4, 5]);
+    let b = vecn([5, 4, 3, 2, 1]);
+    assert_eq!(a + b, vecn([6; 5]));
+    assert_eq!(a * 2 - b, vecn([-3, 0, 3, 6, 9]));
+    assert_eq!(dot(a, b), 35);
+    assert_eq!(a.mul_elem(), 120);
+    assert_eq!(VecN::<i32, 5>::ident(), vecn([1, 0, 0, 0, 0]));
+    assert_eq!(VecN::from(vec3(1.0, 2.0, 3.0)), vecn([1.0, 2.0, 3.0]));
+}
+
+#[test]
+fn empty() {
+    let e = VecN::<f64, 0>::zero();
+    assert_eq!(e.sum_elem(), 0.0);
+    assert_eq!(dot(e, e), 0.0);
+    assert_eq!(e.magnitude(), 0.0);
+    assert_eq!(VecN::<f64, 0>::ident(), e);
+    assert_eq!(matn::<f64, 2, 0>([[], []]) * e, vecn([0.0, 0.0]));
+}
+
+#[test]
+fn parse_format_convert() {
+    let v: VecN<f64, 4> = "(1.5, -2, 3e2, 0)".parse().unwrap();
+    assert_eq!(v, vecn([1.5, -2.0, 300.0, 0.0]));
+    assert!("(1, 2, 3)".parse::<VecN<f64, 4>>().is_err());
+    assert_eq!(format!("{:.1e}", vecn([1500.0, 0.25])), "(1.5e3, 2.5e-1)");
+    assert_eq!(format!("{}", vecn([1, 2, 3])), "(1, 2, 3)");
+    assert_eq!(v.i32(), vecn([1, -2, 300, 0]));
+    assert_eq!(vecn([true, false]).u8(), vecn([1, 0]));
+}
+
+#[test]
+fn mat() {
+    let a = matn([[1, 2, 3], [4, 5, 6]]);
+    let b = matn([[1, 0], [0, 1], [1, 1]]);
+    assert_eq!(a * b, matn([[4, 5], [10, 11]]));
+    assert_eq!(a.transpose(), matn([[1, 4], [2, 5], [3, 6]]));
+    assert_eq!(a * vec3(1, 1, 1), vecn([6, 15]));
+    assert_eq!(Mat::<i32, 3, 3>::default(), Mat::ident());
+    assert_eq!(Mat3::from(Mat::<i32, 3, 3>::ident()), Mat3::ident());
+}